@@ -0,0 +1,605 @@
+//! Foreground/background color contrast validation.
+
+use crate::config::{
+    Color, ContrastOptions, ContrastPolicy, DotsOptions, Gradient, QRCodeStylingOptions,
+};
+use crate::error::{QRError, Result};
+use crate::types::FinderCorner;
+
+/// Foreground layer checked against the background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ContrastTarget {
+    /// Data modules (`DotsOptions`).
+    Dots,
+    /// Finder pattern outer squares (`CornersSquareOptions`).
+    CornersSquare,
+    /// Finder pattern inner dots (`CornersDotOptions`).
+    CornersDot,
+    /// Timing pattern modules (`StructuralOptions::timing`).
+    Timing,
+    /// Alignment patterns (`AlignmentOptions`, or
+    /// `StructuralOptions::alignment`).
+    Alignment,
+    /// Format and version information (`StructuralOptions::format_info`).
+    FormatInfo,
+}
+
+impl ContrastTarget {
+    /// All foreground layers.
+    pub const ALL: [ContrastTarget; 6] = [
+        ContrastTarget::Dots,
+        ContrastTarget::CornersSquare,
+        ContrastTarget::CornersDot,
+        ContrastTarget::Timing,
+        ContrastTarget::Alignment,
+        ContrastTarget::FormatInfo,
+    ];
+}
+
+impl std::fmt::Display for ContrastTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ContrastTarget::Dots => "dots",
+            ContrastTarget::CornersSquare => "corner squares",
+            ContrastTarget::CornersDot => "corner dots",
+            ContrastTarget::Timing => "timing patterns",
+            ContrastTarget::Alignment => "alignment patterns",
+            ContrastTarget::FormatInfo => "format information",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Contrast of one foreground color against its worst background color.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContrastCheck {
+    /// Foreground layer.
+    pub target: ContrastTarget,
+    /// Finder pattern whose own corner style was checked, or `None` for
+    /// the shared style.
+    pub corner: Option<FinderCorner>,
+    /// Gradient stop (or palette) index, or `None` for a solid color.
+    pub stop: Option<usize>,
+    /// `stop` indexes the dots' module color palette.
+    pub palette: bool,
+    /// Foreground color composited over the background.
+    pub foreground: Color,
+    /// Background color giving the worst result.
+    pub background: Color,
+    /// WCAG contrast ratio.
+    pub ratio: f64,
+    /// The foreground is lighter than the background.
+    pub inverted: bool,
+}
+
+impl ContrastCheck {
+    /// Returns true if the check satisfies `options`.
+    pub fn passes(&self, options: &ContrastOptions) -> bool {
+        self.ratio >= options.min_ratio && (options.allow_inverted || !self.inverted)
+    }
+
+    fn describe(&self) -> String {
+        let mut label = self.target.to_string();
+        if let Some(corner) = self.corner {
+            let name = match corner {
+                FinderCorner::TopLeft => "top-left",
+                FinderCorner::TopRight => "top-right",
+                FinderCorner::BottomLeft => "bottom-left",
+            };
+            label.push_str(&format!(" ({})", name));
+        }
+        match self.stop {
+            Some(stop) if self.palette => label.push_str(&format!(" palette color {}", stop)),
+            Some(stop) => label.push_str(&format!(" gradient stop {}", stop)),
+            None => {}
+        }
+        if self.inverted {
+            label.push_str(" (inverted polarity)");
+        }
+        label
+    }
+}
+
+/// Contrast of every foreground fill against the background.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContrastReport {
+    /// Thresholds the checks were judged against.
+    pub options: ContrastOptions,
+    /// One check per foreground color or gradient stop.
+    pub checks: Vec<ContrastCheck>,
+}
+
+impl ContrastReport {
+    /// Returns true if every check passes.
+    pub fn passes(&self) -> bool {
+        self.checks.iter().all(|c| c.passes(&self.options))
+    }
+
+    /// Checks below the threshold or with disallowed polarity.
+    pub fn failures(&self) -> Vec<&ContrastCheck> {
+        self.checks
+            .iter()
+            .filter(|c| !c.passes(&self.options))
+            .collect()
+    }
+
+    /// Lowest-contrast check.
+    pub fn worst(&self) -> Option<&ContrastCheck> {
+        self.checks
+            .iter()
+            .min_by(|a, b| a.ratio.total_cmp(&b.ratio))
+    }
+}
+
+/// Compare every foreground fill with the background.
+///
+/// Semi-transparent fills are composited first, and a transparent
+/// background is treated as white since that is what raster output uses.
+/// Background images, pattern fills and halftone picture pixels are not
+/// sampled; only the background color and the fills' own colors are.
+pub fn analyze_contrast(options: &QRCodeStylingOptions) -> ContrastReport {
+    let contrast_options = &options.contrast_options;
+    let backgrounds = background_colors(options);
+    let mut checks = Vec::new();
+
+    for (target, corner) in layers(options) {
+        // Structural layers are only checked when they have their own style.
+        let Some((color, gradient)) = fill(options, target, corner) else {
+            continue;
+        };
+        let palette = palette(options, target);
+        let stops: Vec<(Option<usize>, Color)> = match (palette, gradient) {
+            (Some(colors), _) => colors
+                .iter()
+                .enumerate()
+                .map(|(i, color)| (Some(i), *color))
+                .collect(),
+            (None, Some(gradient)) => gradient
+                .color_stops
+                .iter()
+                .enumerate()
+                .map(|(i, stop)| (Some(i), stop.color))
+                .collect(),
+            (None, None) => vec![(None, *color)],
+        };
+
+        for (stop, foreground) in stops {
+            let check = backgrounds
+                .iter()
+                .map(|background| {
+                    let composited = foreground.over(background);
+                    ContrastCheck {
+                        target,
+                        corner,
+                        stop,
+                        palette: palette.is_some(),
+                        foreground: composited,
+                        background: *background,
+                        ratio: composited.contrast_ratio(background),
+                        inverted: composited.relative_luminance() > background.relative_luminance(),
+                    }
+                })
+                .min_by(|a, b| {
+                    a.passes(contrast_options)
+                        .cmp(&b.passes(contrast_options))
+                        .then(a.ratio.total_cmp(&b.ratio))
+                })
+                .expect("at least one background color");
+            checks.push(check);
+        }
+    }
+
+    ContrastReport {
+        options: contrast_options.clone(),
+        checks,
+    }
+}
+
+/// Apply the configured [`ContrastPolicy`] to `options`.
+///
+/// Returns the failing checks under [`ContrastPolicy::Warn`] and nothing
+/// otherwise.
+pub(crate) fn enforce_contrast(options: &mut QRCodeStylingOptions) -> Result<Vec<ContrastCheck>> {
+    match options.contrast_options.policy {
+        ContrastPolicy::Ignore => Ok(Vec::new()),
+        ContrastPolicy::Warn => Ok(analyze_contrast(options)
+            .failures()
+            .into_iter()
+            .cloned()
+            .collect()),
+        ContrastPolicy::Error => first_failure(&analyze_contrast(options)).map(|_| Vec::new()),
+        ContrastPolicy::AutoAdjust => {
+            // Each pass moves failing colors 15% of the way to their
+            // extreme, so this converges well before the limit.
+            for _ in 0..40 {
+                let report = analyze_contrast(options);
+                if report.passes() {
+                    return Ok(Vec::new());
+                }
+                for check in report.failures() {
+                    adjust(options, check);
+                }
+            }
+            first_failure(&analyze_contrast(options)).map(|_| Vec::new())
+        }
+    }
+}
+
+fn first_failure(report: &ContrastReport) -> Result<()> {
+    match report.failures().first() {
+        None => Ok(()),
+        Some(check) => Err(QRError::InsufficientContrast {
+            target: check.describe(),
+            ratio: check.ratio,
+            min_ratio: report.options.min_ratio,
+        }),
+    }
+}
+
+/// Push one failing foreground stop away from the background, or the
+/// background away from it once the foreground is at its extreme.
+fn adjust(options: &mut QRCodeStylingOptions, check: &ContrastCheck) {
+    const STEP: f64 = 0.15;
+
+    let lighten = check.inverted && options.contrast_options.allow_inverted;
+    let (extreme, opposite) = if lighten {
+        (Color::WHITE, Color::BLACK)
+    } else {
+        (Color::BLACK, Color::WHITE)
+    };
+
+    let foreground = fill_mut(options, check.target, check.corner, check.stop);
+    let at_extreme =
+        (foreground.r, foreground.g, foreground.b) == (extreme.r, extreme.g, extreme.b);
+    if !at_extreme {
+        *foreground = foreground.mix(&extreme, STEP);
+        return;
+    }
+
+    let background = &mut options.background_options;
+    match background.gradient.as_mut() {
+        Some(gradient) => {
+            for stop in &mut gradient.color_stops {
+                stop.color = stop.color.mix(&opposite, STEP);
+            }
+        }
+        None => background.color = background.color.mix(&opposite, STEP),
+    }
+}
+
+/// Every foreground layer: finder patterns with their own corner style
+/// are checked separately, and the shared style only while some pattern
+/// still uses it.
+fn layers(options: &QRCodeStylingOptions) -> Vec<(ContrastTarget, Option<FinderCorner>)> {
+    let overridden = |target: ContrastTarget, corner: FinderCorner| {
+        let style = options.corners_options.corner(corner);
+        match target {
+            ContrastTarget::CornersSquare => style.square.is_some(),
+            ContrastTarget::CornersDot => style.dot.is_some(),
+            _ => false,
+        }
+    };
+    let mut layers = Vec::new();
+    for target in ContrastTarget::ALL {
+        if !FinderCorner::ALL.iter().all(|&c| overridden(target, c)) {
+            layers.push((target, None));
+        }
+        for corner in FinderCorner::ALL {
+            if overridden(target, corner) {
+                layers.push((target, Some(corner)));
+            }
+        }
+    }
+    layers
+}
+
+fn background_colors(options: &QRCodeStylingOptions) -> Vec<Color> {
+    let background = &options.background_options;
+    let colors: Vec<Color> = match &background.gradient {
+        Some(gradient) if !gradient.color_stops.is_empty() => {
+            gradient.color_stops.iter().map(|s| s.color).collect()
+        }
+        _ => vec![background.color],
+    };
+    colors.iter().map(|c| c.over(&Color::WHITE)).collect()
+}
+
+/// Palette colors painted per module, when they replace the fill.
+///
+/// A custom color function cannot be enumerated and is not checked.
+fn palette(options: &QRCodeStylingOptions, target: ContrastTarget) -> Option<&[Color]> {
+    let dots = dots_options(options, target)?;
+    match (&dots.module_colors, &dots.module_color_fn) {
+        (Some(colors), None) if !colors.colors.is_empty() => Some(&colors.colors),
+        _ => None,
+    }
+}
+
+/// Dots options painting `target`, for the module layers.
+fn dots_options(options: &QRCodeStylingOptions, target: ContrastTarget) -> Option<&DotsOptions> {
+    let structural = &options.structural_options;
+    match target {
+        ContrastTarget::Dots => Some(&options.dots_options),
+        ContrastTarget::Timing => structural.timing.as_ref(),
+        ContrastTarget::Alignment if options.alignment_options.is_some() => None,
+        ContrastTarget::Alignment => structural.alignment.as_ref(),
+        ContrastTarget::FormatInfo => structural.format_info.as_ref(),
+        ContrastTarget::CornersSquare | ContrastTarget::CornersDot => None,
+    }
+}
+
+fn dots_options_mut(
+    options: &mut QRCodeStylingOptions,
+    target: ContrastTarget,
+) -> Option<&mut DotsOptions> {
+    let structural = &mut options.structural_options;
+    match target {
+        ContrastTarget::Dots => Some(&mut options.dots_options),
+        ContrastTarget::Timing => structural.timing.as_mut(),
+        ContrastTarget::Alignment if options.alignment_options.is_some() => None,
+        ContrastTarget::Alignment => structural.alignment.as_mut(),
+        ContrastTarget::FormatInfo => structural.format_info.as_mut(),
+        ContrastTarget::CornersSquare | ContrastTarget::CornersDot => None,
+    }
+}
+
+fn fill(
+    options: &QRCodeStylingOptions,
+    target: ContrastTarget,
+    corner: Option<FinderCorner>,
+) -> Option<(&Color, &Option<Gradient>)> {
+    if let Some(dots) = dots_options(options, target) {
+        return Some((&dots.color, &dots.gradient));
+    }
+    let style = corner.map(|c| options.corners_options.corner(c));
+    match target {
+        ContrastTarget::CornersSquare => {
+            let square = style
+                .and_then(|s| s.square.as_ref())
+                .unwrap_or(&options.corners_square_options);
+            Some((&square.color, &square.gradient))
+        }
+        ContrastTarget::CornersDot => {
+            let dot = style
+                .and_then(|s| s.dot.as_ref())
+                .unwrap_or(&options.corners_dot_options);
+            Some((&dot.color, &dot.gradient))
+        }
+        ContrastTarget::Alignment => options
+            .alignment_options
+            .as_ref()
+            .map(|alignment| (&alignment.color, &alignment.gradient)),
+        _ => None,
+    }
+}
+
+fn fill_mut(
+    options: &mut QRCodeStylingOptions,
+    target: ContrastTarget,
+    corner: Option<FinderCorner>,
+    stop: Option<usize>,
+) -> &mut Color {
+    let has_palette = palette(options, target).is_some();
+    let (color, gradient) = match target {
+        ContrastTarget::CornersSquare => {
+            let square = match corner {
+                Some(c) => options.corners_options.corner_mut(c).square.as_mut(),
+                None => None,
+            }
+            .unwrap_or(&mut options.corners_square_options);
+            (&mut square.color, &mut square.gradient)
+        }
+        ContrastTarget::CornersDot => {
+            let dot = match corner {
+                Some(c) => options.corners_options.corner_mut(c).dot.as_mut(),
+                None => None,
+            }
+            .unwrap_or(&mut options.corners_dot_options);
+            (&mut dot.color, &mut dot.gradient)
+        }
+        ContrastTarget::Alignment if options.alignment_options.is_some() => {
+            let alignment = options
+                .alignment_options
+                .as_mut()
+                .expect("alignment options");
+            (&mut alignment.color, &mut alignment.gradient)
+        }
+        _ => {
+            let dots = dots_options_mut(options, target).expect("checked layer has a style");
+            if let (Some(i), true) = (stop, has_palette) {
+                let colors = dots.module_colors.as_mut().expect("palette");
+                return &mut colors.colors[i];
+            }
+            (&mut dots.color, &mut dots.gradient)
+        }
+    };
+    match (stop, gradient) {
+        (Some(i), Some(gradient)) => &mut gradient.color_stops[i].color,
+        _ => color,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        AlignmentOptions, BackgroundOptions, ColorStop, CornerStyle, CornersOptions,
+        CornersSquareOptions, DotsOptions, ModuleColors, StructuralOptions,
+    };
+    use crate::types::DotType;
+
+    fn pastel_options() -> QRCodeStylingOptions {
+        QRCodeStylingOptions {
+            dots_options: DotsOptions::new(DotType::Rounded).with_gradient(Gradient::linear(vec![
+                ColorStop::new(0.0, Color::rgb(20, 20, 80)),
+                ColorStop::new(1.0, Color::rgb(255, 182, 193)),
+            ])),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_default_passes() {
+        let report = analyze_contrast(&QRCodeStylingOptions::default());
+        assert!(report.passes());
+        assert_eq!(report.checks.len(), 3);
+        assert!((report.worst().unwrap().ratio - 21.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pastel_stop_fails() {
+        let report = analyze_contrast(&pastel_options());
+        let failures = report.failures();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].target, ContrastTarget::Dots);
+        assert_eq!(failures[0].stop, Some(1));
+    }
+
+    #[test]
+    fn test_inverted_polarity() {
+        let mut options = QRCodeStylingOptions {
+            background_options: BackgroundOptions::new(Color::BLACK),
+            ..Default::default()
+        };
+        options.dots_options.color = Color::WHITE;
+        let report = analyze_contrast(&options);
+        assert!(report.checks[0].inverted);
+        assert!(!report.passes());
+
+        options.contrast_options.allow_inverted = true;
+        let report = analyze_contrast(&options);
+        assert!(report.checks[0].passes(&report.options));
+    }
+
+    #[test]
+    fn test_policies() {
+        let mut options = pastel_options();
+        options.contrast_options.policy = ContrastPolicy::Error;
+        assert!(matches!(
+            enforce_contrast(&mut options.clone()),
+            Err(QRError::InsufficientContrast { .. })
+        ));
+
+        options.contrast_options.policy = ContrastPolicy::Warn;
+        let warnings = enforce_contrast(&mut options.clone()).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].stop, Some(1));
+
+        options.contrast_options.policy = ContrastPolicy::AutoAdjust;
+        enforce_contrast(&mut options).unwrap();
+        assert!(analyze_contrast(&options).passes());
+        // The passing stop is left alone.
+        let stops = &options.dots_options.gradient.as_ref().unwrap().color_stops;
+        assert_eq!(stops[0].color, Color::rgb(20, 20, 80));
+    }
+
+    #[test]
+    fn test_module_palette_checked() {
+        let palette = vec![Color::rgb(20, 20, 80), Color::rgb(255, 230, 120)];
+        let mut options = QRCodeStylingOptions {
+            dots_options: DotsOptions::default().with_module_colors(ModuleColors::checker(palette)),
+            ..Default::default()
+        };
+        let report = analyze_contrast(&options);
+        let failures = report.failures();
+        assert_eq!(failures.len(), 1);
+        assert!(failures[0].palette);
+        assert_eq!(failures[0].stop, Some(1));
+
+        options.contrast_options.policy = ContrastPolicy::AutoAdjust;
+        enforce_contrast(&mut options).unwrap();
+        let colors = &options.dots_options.module_colors.as_ref().unwrap().colors;
+        assert_eq!(colors[0], Color::rgb(20, 20, 80));
+        assert_ne!(colors[1], Color::rgb(255, 230, 120));
+    }
+
+    #[test]
+    fn test_structural_styles_checked() {
+        let mut options = QRCodeStylingOptions {
+            structural_options: StructuralOptions::new()
+                .with_alignment(DotsOptions::default().with_color(Color::rgb(250, 220, 220))),
+            ..Default::default()
+        };
+        let report = analyze_contrast(&options);
+        assert_eq!(report.checks.len(), 4);
+        let failures = report.failures();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].target, ContrastTarget::Alignment);
+
+        options.contrast_options.policy = ContrastPolicy::AutoAdjust;
+        enforce_contrast(&mut options).unwrap();
+        assert!(analyze_contrast(&options).passes());
+        assert_eq!(options.dots_options.color, Color::BLACK);
+    }
+
+    #[test]
+    fn test_corner_overrides_checked() {
+        let pale = CornersSquareOptions::default().with_color(Color::rgb(230, 230, 180));
+        let mut options = QRCodeStylingOptions {
+            corners_options: CornersOptions::new()
+                .with_corner(FinderCorner::TopRight, CornerStyle::new().with_square(pale)),
+            ..Default::default()
+        };
+        let report = analyze_contrast(&options);
+        assert_eq!(report.checks.len(), 4);
+        let failures = report.failures();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].target, ContrastTarget::CornersSquare);
+        assert_eq!(failures[0].corner, Some(FinderCorner::TopRight));
+
+        options.contrast_options.policy = ContrastPolicy::AutoAdjust;
+        enforce_contrast(&mut options).unwrap();
+        assert!(analyze_contrast(&options).passes());
+        assert_eq!(options.corners_square_options.color, Color::BLACK);
+    }
+
+    #[test]
+    fn test_alignment_options_checked() {
+        let mut options = QRCodeStylingOptions {
+            alignment_options: Some(
+                AlignmentOptions::default().with_color(Color::rgb(200, 240, 200)),
+            ),
+            // Replaced by the alignment options, so not checked.
+            structural_options: StructuralOptions::new()
+                .with_alignment(DotsOptions::default().with_color(Color::WHITE)),
+            ..Default::default()
+        };
+        let failures: Vec<ContrastTarget> = analyze_contrast(&options)
+            .failures()
+            .iter()
+            .map(|c| c.target)
+            .collect();
+        assert_eq!(failures, vec![ContrastTarget::Alignment]);
+
+        options.contrast_options.policy = ContrastPolicy::AutoAdjust;
+        enforce_contrast(&mut options).unwrap();
+        assert_ne!(
+            options.alignment_options.unwrap().color,
+            Color::rgb(200, 240, 200)
+        );
+    }
+
+    #[test]
+    fn test_contrast_warnings() {
+        use crate::QRCodeStyling;
+
+        let build = |policy: ContrastPolicy| {
+            QRCodeStyling::builder()
+                .data("https://example.com/pastel")
+                .dots_options(DotsOptions::default().with_color(Color::rgb(255, 182, 193)))
+                .contrast_options(ContrastOptions::new(policy))
+                .build()
+        };
+        let qr = build(ContrastPolicy::Warn).unwrap();
+        assert_eq!(qr.contrast_warnings().len(), 1);
+        assert!(build(ContrastPolicy::Ignore)
+            .unwrap()
+            .contrast_warnings()
+            .is_empty());
+        assert!(build(ContrastPolicy::Error).is_err());
+    }
+}
@@ -0,0 +1,267 @@
+//! Logo coverage accounting against Reed-Solomon correction capacity.
+
+use crate::config::QRCodeStylingOptions;
+use crate::core::{QRLayout, QRMatrix};
+use crate::error::{QRError, Result};
+
+/// Codewords one Reed-Solomon block loses under the logo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockDamage {
+    /// Block index.
+    pub block: usize,
+    /// Data codewords in the block.
+    pub data_codewords: usize,
+    /// Error correction codewords in the block.
+    pub ec_codewords: usize,
+    /// Codeword errors the block can correct.
+    pub correctable: usize,
+    /// Data codewords with at least one hidden module.
+    pub damaged_data: usize,
+    /// Error correction codewords with at least one hidden module.
+    pub damaged_ec: usize,
+}
+
+impl BlockDamage {
+    /// Total damaged codewords.
+    pub fn damaged(&self) -> usize {
+        self.damaged_data + self.damaged_ec
+    }
+
+    /// Further codeword errors the block can absorb (negative when it is
+    /// already unrecoverable).
+    pub fn margin(&self) -> isize {
+        self.correctable as isize - self.damaged() as isize
+    }
+}
+
+/// Damage the hidden logo area does to each Reed-Solomon block.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoverageReport {
+    /// Side of the centered square the logo is drawn over, in modules.
+    pub hide_dots: usize,
+    /// Side requested by `ImageOptions::image_size` before fitting.
+    pub requested_hide_dots: usize,
+    /// Function pattern modules (alignment, timing) under the logo.
+    pub hidden_function_modules: usize,
+    /// Per-block damage in block order.
+    pub blocks: Vec<BlockDamage>,
+}
+
+impl CoverageReport {
+    /// Smallest margin over all blocks.
+    pub fn margin(&self) -> isize {
+        self.blocks.iter().map(|b| b.margin()).min().unwrap_or(0)
+    }
+
+    /// Returns true if every block can still correct its damage.
+    pub fn is_recoverable(&self) -> bool {
+        self.margin() >= 0
+    }
+
+    /// Returns true if the logo is drawn smaller than `image_size` asked
+    /// for, so that every block stays recoverable.
+    pub fn is_shrunk(&self) -> bool {
+        self.hide_dots < self.requested_hide_dots
+    }
+
+    /// Block with the smallest margin.
+    pub fn worst_block(&self) -> Option<&BlockDamage> {
+        self.blocks.iter().min_by_key(|b| b.margin())
+    }
+}
+
+/// Account for the codewords destroyed by hiding a centered square of
+/// `hide_dots` modules per side.
+fn block_damage(layout: &QRLayout, hide_dots: usize) -> (Vec<BlockDamage>, usize) {
+    let mut damaged: Vec<Vec<bool>> = layout
+        .blocks()
+        .iter()
+        .map(|b| vec![false; b.data_codewords + b.ec_codewords])
+        .collect();
+    let mut hidden_function_modules = 0;
+
+    let size = layout.size();
+    let start = (size - hide_dots.min(size)) / 2;
+    for row in start..start + hide_dots.min(size) {
+        for col in start..start + hide_dots.min(size) {
+            match layout.codeword(row, col) {
+                Some(cw) => damaged[cw.block][cw.index] = true,
+                None if layout.kind(row, col).is_function() => hidden_function_modules += 1,
+                None => {}
+            }
+        }
+    }
+
+    let blocks = layout
+        .blocks()
+        .iter()
+        .zip(&damaged)
+        .enumerate()
+        .map(|(block, (info, hit))| {
+            let (data, ec) = hit.split_at(info.data_codewords);
+            BlockDamage {
+                block,
+                data_codewords: info.data_codewords,
+                ec_codewords: info.ec_codewords,
+                correctable: info.correctable,
+                damaged_data: data.iter().filter(|&&h| h).count(),
+                damaged_ec: ec.iter().filter(|&&h| h).count(),
+            }
+        })
+        .collect();
+    (blocks, hidden_function_modules)
+}
+
+/// Side of the area `image_size` asks for, before any capacity check.
+///
+/// The image may claim `image_size` of the level's nominal recovery
+/// percentage of all modules, as an odd-sided centered square.
+fn requested_hide_dots(options: &QRCodeStylingOptions, count: usize) -> usize {
+    if options.image.is_none() {
+        return 0;
+    }
+    let error_correction_percent = options.qr_options.error_correction_level.percentage();
+    let cover_level = options.image_options.image_size * error_correction_percent;
+    let max_hidden_dots = (cover_level * (count * count) as f64).floor() as usize;
+
+    let mut hide_dots = (max_hidden_dots as f64).sqrt().floor() as usize;
+    if hide_dots.is_multiple_of(2) {
+        hide_dots = hide_dots.saturating_sub(1);
+    }
+    hide_dots.min(count.saturating_sub(14))
+}
+
+/// Coverage of the area the logo will actually hide.
+///
+/// The requested area shrinks two modules at a time until every block can
+/// correct its damage, which [`CoverageReport::is_shrunk`] reports. With
+/// `ImageOptions::strict_coverage` an unrecoverable request fails with
+/// [`QRError::LogoCoverage`] instead.
+///
+/// Modules under the logo count as damaged even without
+/// `ImageOptions::hide_background_dots`, since an opaque logo covers them
+/// anyway. Only a logo with transparent areas leaves some of them
+/// readable, so the report is conservative for such logos.
+pub(crate) fn coverage(
+    options: &QRCodeStylingOptions,
+    matrix: &QRMatrix,
+) -> Result<CoverageReport> {
+    let layout = matrix.layout();
+    let requested_hide_dots = requested_hide_dots(options, layout.size());
+
+    let mut hide_dots = requested_hide_dots;
+    loop {
+        let (blocks, hidden_function_modules) = block_damage(&layout, hide_dots);
+        let report = CoverageReport {
+            hide_dots,
+            requested_hide_dots,
+            hidden_function_modules,
+            blocks,
+        };
+        if report.is_recoverable() || hide_dots == 0 {
+            return Ok(report);
+        }
+        if options.image_options.strict_coverage {
+            let worst = report
+                .worst_block()
+                .expect("unrecoverable report has blocks");
+            return Err(QRError::LogoCoverage {
+                block: worst.block,
+                damaged: worst.damaged(),
+                correctable: worst.correctable,
+            });
+        }
+        hide_dots = hide_dots.saturating_sub(2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{ImageOptions, QROptions};
+    use crate::types::ErrorCorrectionLevel;
+
+    fn options(level: ErrorCorrectionLevel, image_size: f64) -> QRCodeStylingOptions {
+        QRCodeStylingOptions {
+            data: "https://example.com/coverage".to_string(),
+            image: Some(vec![0x89, 0x50, 0x4E, 0x47]),
+            image_options: ImageOptions::new().with_image_size(image_size),
+            qr_options: QROptions::new().with_error_correction_level(level),
+            ..Default::default()
+        }
+    }
+
+    fn matrix(options: &QRCodeStylingOptions) -> QRMatrix {
+        QRMatrix::new(&options.data, &options.qr_options).unwrap()
+    }
+
+    #[test]
+    fn test_no_image_no_damage() {
+        let mut options = options(ErrorCorrectionLevel::H, 0.4);
+        options.image = None;
+        let report = coverage(&options, &matrix(&options)).unwrap();
+        assert_eq!(report.hide_dots, 0);
+        assert!(report.blocks.iter().all(|b| b.damaged() == 0));
+        assert_eq!(
+            report.margin(),
+            report
+                .blocks
+                .iter()
+                .map(|b| b.correctable as isize)
+                .min()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_damage_counts_codewords() {
+        let options = options(ErrorCorrectionLevel::H, 0.4);
+        let layout = matrix(&options).layout();
+        let (blocks, _) = block_damage(&layout, 3);
+        // Nine modules touch at most nine codewords and at least two.
+        let damaged: usize = blocks.iter().map(|b| b.damaged()).sum();
+        assert!((2..=9).contains(&damaged));
+    }
+
+    #[test]
+    fn test_oversized_logo_shrinks() {
+        let options = options(ErrorCorrectionLevel::L, 1.0);
+        let matrix = matrix(&options);
+        let report = coverage(&options, &matrix).unwrap();
+        assert!(report.is_recoverable());
+        assert!(report.is_shrunk());
+        assert_eq!(report.hide_dots % 2, 1);
+    }
+
+    #[test]
+    fn test_visible_dots_still_damaged() {
+        let mut options = options_with_strict(ErrorCorrectionLevel::L, 1.0);
+        options.image_options.hide_background_dots = false;
+        // The logo is drawn over the modules, so they are lost all the same.
+        assert!(matches!(
+            coverage(&options, &matrix(&options)),
+            Err(QRError::LogoCoverage { .. })
+        ));
+    }
+
+    fn options_with_strict(level: ErrorCorrectionLevel, image_size: f64) -> QRCodeStylingOptions {
+        let mut options = options(level, image_size);
+        options.image_options.strict_coverage = true;
+        options
+    }
+
+    #[test]
+    fn test_strict_coverage_errors() {
+        let options = options_with_strict(ErrorCorrectionLevel::L, 1.0);
+        assert!(matches!(
+            coverage(&options, &matrix(&options)),
+            Err(QRError::LogoCoverage { .. })
+        ));
+
+        let options = options_with_strict(ErrorCorrectionLevel::H, 0.3);
+        let report = coverage(&options, &matrix(&options)).unwrap();
+        assert_eq!(report.hide_dots, report.requested_hide_dots);
+    }
+}
@@ -0,0 +1,155 @@
+//! Recovering the exact symbol from an image of an existing QR code.
+
+use image::GrayImage;
+use qrcode::Version;
+
+use super::{luminance_threshold, Decoded, LOW_CONTRAST};
+use crate::config::QROptions;
+use crate::core::{mask_bit, QRLayout, QRMatrix};
+use crate::error::{QRError, Result};
+
+/// Find the first decodable QR code in `image` and rebuild its matrix.
+///
+/// When every Reed-Solomon block reads back without errors the sampled
+/// codewords are reused as they are. Otherwise the decoded data is
+/// re-encoded with the same version, error correction level and mask.
+pub(crate) fn import(image: &GrayImage) -> Result<(Decoded, QRMatrix)> {
+    if luminance_threshold(image).is_none() {
+        return Err(QRError::DecodeError(LOW_CONTRAST.to_string()));
+    }
+    let mut prepared = rqrr::PreparedImage::prepare(image.clone());
+
+    let mut last_error = "no QR code found in image".to_string();
+    for grid in prepared.detect_grids() {
+        match grid.decode() {
+            Ok((meta, data)) => {
+                let decoded = Decoded::new(meta, data);
+                let matrix = match sampled_matrix(&grid.grid, &decoded)? {
+                    Some(matrix) => matrix,
+                    None => reencode(&decoded)?,
+                };
+                return Ok((decoded, matrix));
+            }
+            Err(e) => last_error = e.to_string(),
+        }
+    }
+    Err(QRError::DecodeError(last_error))
+}
+
+/// Rebuild from the sampled codewords, or `None` if any block needed
+/// error correction (or the grid was read mirrored).
+fn sampled_matrix(grid: &dyn rqrr::BitGrid, decoded: &Decoded) -> Result<Option<QRMatrix>> {
+    let layout = QRLayout::new(decoded.version, decoded.error_correction_level)?;
+    if grid.size() != layout.size() {
+        return Ok(None);
+    }
+
+    let mut blocks: Vec<Vec<u8>> = layout
+        .blocks()
+        .iter()
+        .map(|b| vec![0; b.data_codewords + b.ec_codewords])
+        .collect();
+    for row in 0..layout.size() {
+        for col in 0..layout.size() {
+            if let Some(cw) = layout.codeword(row, col) {
+                if grid.bit(row, col) ^ mask_bit(decoded.mask, row, col) {
+                    blocks[cw.block][cw.index] |= 1 << cw.bit;
+                }
+            }
+        }
+    }
+
+    for (codewords, info) in blocks.iter().zip(layout.blocks()) {
+        let (data, ec) = codewords.split_at(info.data_codewords);
+        if qrcode::ec::create_error_correction_code(data, info.ec_codewords) != ec {
+            return Ok(None);
+        }
+    }
+
+    let (data, ec): (Vec<_>, Vec<_>) = layout
+        .codeword_order()
+        .into_iter()
+        .partition(|&(block, index)| index < layout.blocks()[block].data_codewords);
+    let data: Vec<u8> = data.into_iter().map(|(b, i)| blocks[b][i]).collect();
+    let ec: Vec<u8> = ec.into_iter().map(|(b, i)| blocks[b][i]).collect();
+
+    QRMatrix::from_codewords(
+        Version::Normal(decoded.version as i16),
+        decoded.error_correction_level,
+        decoded.mask,
+        &data,
+        &ec,
+    )
+    .map(Some)
+}
+
+fn reencode(decoded: &Decoded) -> Result<QRMatrix> {
+    let options = QROptions::new()
+        .with_type_number(decoded.version)
+        .with_error_correction_level(decoded.error_correction_level)
+        .with_mask_pattern(decoded.mask);
+    QRMatrix::new(&decoded.data, &options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ErrorCorrectionLevel;
+
+    /// Flat black-on-white rendering with a four-module quiet zone.
+    fn flat_image(matrix: &QRMatrix, flip: Option<(usize, usize)>) -> GrayImage {
+        const SCALE: usize = 6;
+        let side = (matrix.size() + 8) * SCALE;
+        GrayImage::from_fn(side as u32, side as u32, |x, y| {
+            let col = (x as usize / SCALE).wrapping_sub(4);
+            let row = (y as usize / SCALE).wrapping_sub(4);
+            let dark = matrix.is_dark(row, col) ^ (flip == Some((row, col)));
+            image::Luma([if dark { 0 } else { 255 }])
+        })
+    }
+
+    fn same_modules(a: &QRMatrix, b: &QRMatrix) -> bool {
+        a.size() == b.size()
+            && (0..a.size()).all(|r| (0..a.size()).all(|c| a.is_dark(r, c) == b.is_dark(r, c)))
+    }
+
+    fn source(mask: u8) -> QRMatrix {
+        let options = QROptions::new()
+            .with_error_correction_level(ErrorCorrectionLevel::M)
+            .with_mask_pattern(mask);
+        QRMatrix::new("https://example.com/restyle", &options).unwrap()
+    }
+
+    #[test]
+    fn test_import_recovers_parameters() {
+        for mask in [0, 3, 6] {
+            let original = source(mask);
+            let (decoded, matrix) = import(&flat_image(&original, None)).unwrap();
+            assert_eq!(decoded.data, "https://example.com/restyle");
+            assert_eq!(decoded.mask, mask);
+            assert_eq!(decoded.error_correction_level, ErrorCorrectionLevel::M);
+            assert_eq!(decoded.version, original.version());
+            assert!(same_modules(&original, &matrix));
+        }
+    }
+
+    #[test]
+    fn test_damaged_module_is_repaired() {
+        let original = source(2);
+        let layout = original.layout();
+        let center = original.size() / 2;
+        let (row, col) = (center..original.size())
+            .flat_map(|r| (0..original.size()).map(move |c| (r, c)))
+            .find(|&(r, c)| layout.codeword(r, c).is_some())
+            .unwrap();
+
+        let (_, matrix) = import(&flat_image(&original, Some((row, col)))).unwrap();
+        assert!(same_modules(&original, &matrix));
+    }
+
+    #[test]
+    fn test_no_code() {
+        let blank = GrayImage::from_fn(64, 64, |x, _| image::Luma([if x < 32 { 0 } else { 255 }]));
+        assert!(matches!(import(&blank), Err(QRError::DecodeError(_))));
+    }
+}
@@ -0,0 +1,247 @@
+//! Scannability analysis of rendered QR codes.
+//!
+//! Rendered output is rasterized and read back with a pure-Rust decoder so
+//! that styling choices can be checked before anything is printed.
+
+mod contrast;
+mod coverage;
+mod import;
+mod robustness;
+mod verify;
+
+/// Re-exported from [`types`](crate::types), where corner styling also uses it.
+pub use crate::types::FinderCorner;
+pub(crate) use contrast::enforce_contrast;
+pub use contrast::{analyze_contrast, ContrastCheck, ContrastReport, ContrastTarget};
+pub(crate) use coverage::coverage;
+pub use coverage::{BlockDamage, CoverageReport};
+pub(crate) use import::import;
+pub(crate) use robustness::robustness;
+pub use robustness::{
+    Degradation, DegradationResult, LevelResult, RobustnessOptions, RobustnessReport,
+};
+pub(crate) use verify::verify;
+pub use verify::{FinderStatus, VerifyReport};
+
+use image::GrayImage;
+
+use crate::config::QRCodeStylingOptions;
+use crate::core::QRMatrix;
+use crate::error::Result;
+use crate::rendering::{RasterRenderer, SvgRenderer};
+use crate::types::ErrorCorrectionLevel;
+
+const LOW_CONTRAST: &str = "contrast between dark and light modules is too low";
+
+/// Result of reading a QR code out of a grayscale image.
+#[derive(Debug, Clone)]
+pub(crate) struct Decoded {
+    pub data: String,
+    pub version: u8,
+    pub error_correction_level: ErrorCorrectionLevel,
+    pub mask: u8,
+}
+
+/// Render the styled code at `scale` times its configured size.
+///
+/// A white quiet zone of four modules is added around the result: that is
+/// the printer's job, so the modules are judged on their own.
+pub(crate) fn rasterize(
+    options: &QRCodeStylingOptions,
+    matrix: &QRMatrix,
+    scale: f64,
+) -> Result<(GrayImage, GridGeometry)> {
+    let svg = SvgRenderer::new(options.clone()).render(matrix)?;
+    let width = ((options.width as f64 * scale).round() as u32).max(1);
+    let height = ((options.height as f64 * scale).round() as u32).max(1);
+    let image = RasterRenderer::svg_to_image(&svg, width, height)?.to_luma8();
+    let geometry = grid_geometry(options, matrix).scaled(scale);
+
+    let padding = (geometry.dot_size * 4.0).ceil() as u32;
+    Ok((pad_image(&image, padding), geometry.offset(padding as f64)))
+}
+
+/// Module grid position at the configured output size.
+pub(crate) fn grid_geometry(options: &QRCodeStylingOptions, matrix: &QRMatrix) -> GridGeometry {
+    let renderer = SvgRenderer::new(options.clone());
+    let count = matrix.module_count();
+    let (x, y) = renderer.grid_origin(count);
+    GridGeometry {
+        x,
+        y,
+        dot_size: renderer.dot_size(count),
+        count,
+    }
+}
+
+/// Detect and decode the first readable QR code in `image`.
+pub(crate) fn decode_image(image: &GrayImage) -> std::result::Result<Decoded, String> {
+    if luminance_threshold(image).is_none() {
+        return Err(LOW_CONTRAST.to_string());
+    }
+    let mut prepared = rqrr::PreparedImage::prepare(image.clone());
+    let grids = prepared.detect_grids();
+    if grids.is_empty() {
+        return Err("no QR code found in image".to_string());
+    }
+
+    let mut last_error = String::new();
+    for grid in &grids {
+        match grid.decode() {
+            Ok((meta, data)) => return Ok(Decoded::new(meta, data)),
+            Err(e) => last_error = e.to_string(),
+        }
+    }
+    Err(last_error)
+}
+
+/// Pixel position of the module grid inside a rasterized image.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GridGeometry {
+    pub x: f64,
+    pub y: f64,
+    pub dot_size: f64,
+    pub count: usize,
+}
+
+impl GridGeometry {
+    /// Scale every pixel measure by `factor`.
+    pub fn scaled(self, factor: f64) -> Self {
+        Self {
+            x: self.x * factor,
+            y: self.y * factor,
+            dot_size: self.dot_size * factor,
+            count: self.count,
+        }
+    }
+
+    /// Shift the origin by `offset` pixels on both axes.
+    pub fn offset(self, offset: f64) -> Self {
+        Self {
+            x: self.x + offset,
+            y: self.y + offset,
+            ..self
+        }
+    }
+}
+
+/// Decode by sampling module centers at a known grid position.
+///
+/// The detector's finder search expects square eyes, so rounded finder
+/// styles are read this way instead. Each module is judged by the mean of
+/// its central third.
+pub(crate) fn decode_grid(
+    image: &GrayImage,
+    geometry: &GridGeometry,
+) -> std::result::Result<Decoded, String> {
+    let threshold = luminance_threshold(image).ok_or_else(|| LOW_CONTRAST.to_string())?;
+    let (width, height) = (image.width() as f64, image.height() as f64);
+    let dot_size = geometry.dot_size;
+    let inset = dot_size / 3.0;
+    let is_dark = |col: usize, row: usize| -> bool {
+        let left = geometry.x + col as f64 * dot_size;
+        let top = geometry.y + row as f64 * dot_size;
+        let x0 = (left + inset).floor().clamp(0.0, width - 1.0) as u32;
+        let y0 = (top + inset).floor().clamp(0.0, height - 1.0) as u32;
+        let x1 = ((left + dot_size - inset).ceil().min(width) as u32).max(x0 + 1);
+        let y1 = ((top + dot_size - inset).ceil().min(height) as u32).max(y0 + 1);
+
+        let mut sum = 0u64;
+        let mut n = 0u64;
+        for y in y0..y1 {
+            for x in x0..x1 {
+                sum += image.get_pixel(x, y).0[0] as u64;
+                n += 1;
+            }
+        }
+        ((sum / n) as u16) < threshold
+    };
+
+    let grid = rqrr::Grid::new(rqrr::SimpleGrid::from_func(geometry.count, is_dark));
+    grid.decode()
+        .map(|(meta, data)| Decoded::new(meta, data))
+        .map_err(|e| e.to_string())
+}
+
+/// Run the detector, falling back to grid sampling when `geometry` is known.
+///
+/// The returned flag is set when the fallback was needed.
+pub(crate) fn read_back(
+    image: &GrayImage,
+    geometry: Option<&GridGeometry>,
+) -> (std::result::Result<Decoded, String>, bool) {
+    match (decode_image(image), geometry) {
+        (Ok(decoded), _) => (Ok(decoded), false),
+        (Err(error), None) => (Err(error), false),
+        (Err(error), Some(geometry)) => (decode_grid(image, geometry).map_err(|_| error), true),
+    }
+}
+
+impl Decoded {
+    fn new(meta: rqrr::MetaData, data: String) -> Self {
+        Self {
+            data,
+            version: meta.version.0 as u8,
+            error_correction_level: level_from_format_bits(meta.ecc_level),
+            mask: meta.mask as u8,
+        }
+    }
+}
+
+/// Smallest dark-to-light luminance spread treated as readable.
+///
+/// The software decoder binarizes adaptively and reads contrast no camera
+/// would, so anything flatter than this is reported as unreadable.
+const MIN_LUMINANCE_RANGE: u8 = 64;
+
+/// Midpoint between the darkest and lightest pixel, or `None` when the
+/// image is too flat to read.
+pub(crate) fn luminance_threshold(image: &GrayImage) -> Option<u16> {
+    let (min, max) = image.pixels().fold((u8::MAX, u8::MIN), |(lo, hi), p| {
+        (lo.min(p.0[0]), hi.max(p.0[0]))
+    });
+    if max.saturating_sub(min) < MIN_LUMINANCE_RANGE {
+        return None;
+    }
+    Some((min as u16 + max as u16).div_ceil(2))
+}
+
+/// Map the two format-information EC bits to a level.
+fn level_from_format_bits(bits: u16) -> ErrorCorrectionLevel {
+    match bits & 0b11 {
+        0b01 => ErrorCorrectionLevel::L,
+        0b00 => ErrorCorrectionLevel::M,
+        0b11 => ErrorCorrectionLevel::Q,
+        _ => ErrorCorrectionLevel::H,
+    }
+}
+
+/// Add a white border of `padding` pixels on every side.
+pub(crate) fn pad_image(image: &GrayImage, padding: u32) -> GrayImage {
+    let mut padded = GrayImage::from_pixel(
+        image.width() + padding * 2,
+        image.height() + padding * 2,
+        image::Luma([255]),
+    );
+    image::imageops::overlay(&mut padded, image, padding as i64, padding as i64);
+    padded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_from_format_bits() {
+        assert_eq!(level_from_format_bits(0), ErrorCorrectionLevel::M);
+        assert_eq!(level_from_format_bits(1), ErrorCorrectionLevel::L);
+        assert_eq!(level_from_format_bits(2), ErrorCorrectionLevel::H);
+        assert_eq!(level_from_format_bits(3), ErrorCorrectionLevel::Q);
+    }
+
+    #[test]
+    fn test_decode_blank_image() {
+        let image = GrayImage::from_pixel(64, 64, image::Luma([255]));
+        assert!(decode_image(&image).is_err());
+    }
+}
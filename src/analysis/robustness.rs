@@ -0,0 +1,419 @@
+//! Stress testing rendered codes against shrinking and image degradation.
+
+use image::GrayImage;
+
+use super::{grid_geometry, rasterize, read_back, GridGeometry};
+use crate::config::QRCodeStylingOptions;
+use crate::core::QRMatrix;
+use crate::error::{QRError, Result};
+use crate::utils::SeededRng;
+
+/// Simulated capture degradation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Degradation {
+    /// Gaussian blur; level is sigma in modules.
+    Blur,
+    /// JPEG re-encoding; level is the quality (lower is worse).
+    Jpeg,
+    /// Gaussian noise; level is the standard deviation in luminance steps.
+    Noise,
+    /// Keystone skew; level is the fraction the top edge is narrowed by.
+    Perspective,
+    /// Washed-out print; level is the contrast left (lower is worse).
+    LowContrast,
+}
+
+impl Degradation {
+    /// All degradations.
+    pub const ALL: [Degradation; 5] = [
+        Degradation::Blur,
+        Degradation::Jpeg,
+        Degradation::Noise,
+        Degradation::Perspective,
+        Degradation::LowContrast,
+    ];
+
+    /// Levels tested, ordered from mild to severe.
+    pub fn levels(&self) -> &'static [f64] {
+        match self {
+            Degradation::Blur => &[0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.8, 1.0],
+            Degradation::Jpeg => &[90.0, 75.0, 50.0, 30.0, 20.0, 10.0, 5.0, 1.0],
+            Degradation::Noise => &[8.0, 16.0, 24.0, 32.0, 48.0, 64.0, 96.0, 128.0],
+            Degradation::Perspective => &[0.05, 0.1, 0.15, 0.2, 0.25, 0.3, 0.4],
+            Degradation::LowContrast => &[0.8, 0.6, 0.5, 0.4, 0.3, 0.25, 0.2, 0.15],
+        }
+    }
+
+    fn apply(
+        &self,
+        image: &GrayImage,
+        level: f64,
+        dot_size: f64,
+        rng: &mut SeededRng,
+    ) -> Result<GrayImage> {
+        let image = match self {
+            Degradation::Blur => image::imageops::blur(image, (level * dot_size) as f32),
+            Degradation::Jpeg => {
+                let mut bytes = Vec::new();
+                image::codecs::jpeg::JpegEncoder::new_with_quality(&mut bytes, level as u8)
+                    .encode_image(image)
+                    .map_err(|e| QRError::ImageEncodeError(e.to_string()))?;
+                image::load_from_memory(&bytes)?.to_luma8()
+            }
+            Degradation::Noise => {
+                let mut noisy = image.clone();
+                for pixel in noisy.pixels_mut() {
+                    let value = pixel.0[0] as f64 + rng.gaussian() * level;
+                    pixel.0[0] = value.round().clamp(0.0, 255.0) as u8;
+                }
+                noisy
+            }
+            Degradation::Perspective => keystone(image, level),
+            Degradation::LowContrast => {
+                let mut faded = image.clone();
+                for pixel in faded.pixels_mut() {
+                    pixel.0[0] = (255.0 - (255.0 - pixel.0[0] as f64) * level).round() as u8;
+                }
+                faded
+            }
+        };
+        Ok(image)
+    }
+}
+
+/// Settings for [`QRCodeStyling::robustness`](crate::QRCodeStyling::robustness).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RobustnessOptions {
+    /// Module sizes in pixels to try; they are tried largest first.
+    pub module_pixel_sizes: Vec<f64>,
+    /// Module size in pixels used for the degradation runs.
+    pub reference_module_pixels: f64,
+    /// Degradations to run.
+    pub degradations: Vec<Degradation>,
+    /// Seed for the noise degradation.
+    pub seed: u64,
+}
+
+impl Default for RobustnessOptions {
+    fn default() -> Self {
+        Self {
+            module_pixel_sizes: vec![10.0, 8.0, 6.0, 5.0, 4.0, 3.0, 2.5, 2.0, 1.5, 1.0],
+            reference_module_pixels: 6.0,
+            degradations: Degradation::ALL.to_vec(),
+            seed: 0,
+        }
+    }
+}
+
+impl RobustnessOptions {
+    /// Create options with the default sizes and all degradations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the module sizes to try.
+    pub fn with_module_pixel_sizes(mut self, sizes: Vec<f64>) -> Self {
+        self.module_pixel_sizes = sizes;
+        self
+    }
+
+    /// Set the module size used for degradation runs.
+    pub fn with_reference_module_pixels(mut self, pixels: f64) -> Self {
+        self.reference_module_pixels = pixels;
+        self
+    }
+
+    /// Set the degradations to run.
+    pub fn with_degradations(mut self, degradations: Vec<Degradation>) -> Self {
+        self.degradations = degradations;
+        self
+    }
+
+    /// Set the noise seed.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+}
+
+/// Decode result for one tested level.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LevelResult {
+    /// Tested level (module pixels or degradation level).
+    pub level: f64,
+    /// The code decoded to the input data.
+    pub decoded: bool,
+    /// It only decoded by sampling modules at their known positions, so
+    /// the level does not count as passing.
+    pub grid_sampled: bool,
+}
+
+impl LevelResult {
+    /// Returns true if the detector found the code and it decoded.
+    pub fn passed(&self) -> bool {
+        self.decoded && !self.grid_sampled
+    }
+}
+
+/// Outcome of one degradation run.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DegradationResult {
+    /// Which degradation.
+    pub degradation: Degradation,
+    /// Levels tested, up to and including the first failure.
+    pub levels: Vec<LevelResult>,
+    /// Most severe level that passed with every milder level also passing.
+    pub worst_passing: Option<f64>,
+}
+
+/// Outcome of [`QRCodeStyling::robustness`](crate::QRCodeStyling::robustness).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RobustnessReport {
+    /// Modules per side.
+    pub module_count: usize,
+    /// Module sizes tested, up to and including the first failure.
+    pub sizes: Vec<LevelResult>,
+    /// Smallest module size in pixels at which the detector still found
+    /// and decoded the code.
+    pub min_module_pixels: Option<f64>,
+    /// Per-degradation results at the reference module size.
+    pub degradations: Vec<DegradationResult>,
+}
+
+impl RobustnessReport {
+    /// Smallest printed width of the module grid in millimetres.
+    ///
+    /// `scanner_dpi` is the effective camera resolution on the printed
+    /// surface at the intended scanning distance, in pixels per inch.
+    pub fn min_print_size_mm(&self, scanner_dpi: f64) -> Option<f64> {
+        self.min_module_pixels
+            .map(|pixels| pixels * self.module_count as f64 / scanner_dpi * 25.4)
+    }
+
+    /// Result for one degradation, if it was run.
+    pub fn degradation(&self, degradation: Degradation) -> Option<&DegradationResult> {
+        self.degradations
+            .iter()
+            .find(|d| d.degradation == degradation)
+    }
+}
+
+/// Shrink and degrade the styled code until it stops decoding.
+pub(crate) fn robustness(
+    options: &QRCodeStylingOptions,
+    matrix: &QRMatrix,
+    robustness_options: &RobustnessOptions,
+) -> Result<RobustnessReport> {
+    let native = grid_geometry(options, matrix);
+    // Modules rounded down to nothing cannot be scaled to any size.
+    if native.dot_size <= 0.0 {
+        return Err(QRError::CanvasTooSmall {
+            width: options.width,
+            height: options.height,
+        });
+    }
+    let read = |level: f64, image: &GrayImage, geometry: Option<&GridGeometry>| {
+        let (decoded, grid_sampled) = read_back(image, geometry);
+        LevelResult {
+            level,
+            decoded: decoded.is_ok_and(|decoded| decoded.data == options.data),
+            grid_sampled,
+        }
+    };
+
+    let mut module_pixel_sizes = robustness_options.module_pixel_sizes.clone();
+    module_pixel_sizes.sort_by(|a, b| b.total_cmp(a));
+
+    let mut sizes = Vec::new();
+    for &pixels in &module_pixel_sizes {
+        let (image, geometry) = rasterize(options, matrix, pixels / native.dot_size)?;
+        let result = read(pixels, &image, Some(&geometry));
+        sizes.push(result);
+        if !result.passed() {
+            break;
+        }
+    }
+    let min_module_pixels = passing_run(&sizes);
+
+    let scale = robustness_options.reference_module_pixels / native.dot_size;
+    let (reference, geometry) = rasterize(options, matrix, scale)?;
+    let mut rng = SeededRng::new(robustness_options.seed);
+
+    let mut degradations = Vec::new();
+    for &degradation in &robustness_options.degradations {
+        // Skew moves modules away from the known grid, so only the
+        // detector can read it.
+        let geometry = (degradation != Degradation::Perspective).then_some(&geometry);
+
+        let mut levels = Vec::new();
+        for &level in degradation.levels() {
+            let degraded =
+                degradation.apply(&reference, level, scale * native.dot_size, &mut rng)?;
+            let result = read(level, &degraded, geometry);
+            levels.push(result);
+            if !result.passed() {
+                break;
+            }
+        }
+        degradations.push(DegradationResult {
+            degradation,
+            worst_passing: passing_run(&levels),
+            levels,
+        });
+    }
+
+    Ok(RobustnessReport {
+        module_count: matrix.module_count(),
+        sizes,
+        min_module_pixels,
+        degradations,
+    })
+}
+
+/// Last level of the leading run of passing results.
+fn passing_run(levels: &[LevelResult]) -> Option<f64> {
+    levels
+        .iter()
+        .take_while(|l| l.passed())
+        .last()
+        .map(|l| l.level)
+}
+
+/// Narrow the top edge by `amount` while keeping the bottom edge, as seen
+/// by a camera tilted back from the print.
+fn keystone(image: &GrayImage, amount: f64) -> GrayImage {
+    let (width, height) = image.dimensions();
+    let cx = width as f64 / 2.0;
+    let sample = |x: f64, y: f64| -> f64 {
+        if x < 0.0 || y < 0.0 || x > (width - 1) as f64 || y > (height - 1) as f64 {
+            return 255.0;
+        }
+        let (x0, y0) = (x.floor(), y.floor());
+        let (fx, fy) = (x - x0, y - y0);
+        let p = |dx: u32, dy: u32| {
+            let px = (x0 as u32 + dx).min(width - 1);
+            let py = (y0 as u32 + dy).min(height - 1);
+            image.get_pixel(px, py).0[0] as f64
+        };
+        (p(0, 0) * (1.0 - fx) + p(1, 0) * fx) * (1.0 - fy)
+            + (p(0, 1) * (1.0 - fx) + p(1, 1) * fx) * fy
+    };
+
+    GrayImage::from_fn(width, height, |x, y| {
+        let row_scale = 1.0 - amount * (1.0 - y as f64 / height as f64);
+        let source_x = cx + (x as f64 + 0.5 - cx) / row_scale - 0.5;
+        image::Luma([sample(source_x, y as f64).round() as u8])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::QROptions;
+
+    #[test]
+    fn test_passing_run() {
+        let levels = [
+            LevelResult {
+                level: 1.0,
+                decoded: true,
+                grid_sampled: false,
+            },
+            LevelResult {
+                level: 2.0,
+                decoded: true,
+                grid_sampled: false,
+            },
+            LevelResult {
+                level: 3.0,
+                decoded: true,
+                grid_sampled: true,
+            },
+            LevelResult {
+                level: 4.0,
+                decoded: true,
+                grid_sampled: false,
+            },
+        ];
+        assert_eq!(passing_run(&levels), Some(2.0));
+        // A level that only decoded by grid sampling ends the run.
+        assert_eq!(passing_run(&levels[2..]), None);
+    }
+
+    #[test]
+    fn test_keystone() {
+        let image = GrayImage::from_pixel(20, 20, image::Luma([100]));
+        let skewed = keystone(&image, 0.3);
+        assert_eq!(skewed.get_pixel(10, 10).0[0], 100);
+        assert_eq!(skewed.get_pixel(10, 19).0[0], 100);
+        // The top row is squeezed inward, so its ends fall outside the source.
+        assert_eq!(skewed.get_pixel(0, 0).0[0], 255);
+    }
+
+    #[test]
+    fn test_robustness() {
+        let options = QRCodeStylingOptions {
+            data: "https://example.com".to_string(),
+            ..Default::default()
+        };
+        let matrix = QRMatrix::new(&options.data, &options.qr_options).unwrap();
+        let robustness_options = RobustnessOptions::new()
+            .with_module_pixel_sizes(vec![6.0, 3.0, 0.5])
+            .with_degradations(vec![Degradation::LowContrast, Degradation::Noise]);
+
+        let report = robustness(&options, &matrix, &robustness_options).unwrap();
+        assert_eq!(report.min_module_pixels, Some(3.0));
+        assert!(!report.sizes.last().unwrap().decoded);
+
+        let contrast = report.degradation(Degradation::LowContrast).unwrap();
+        assert!(contrast.worst_passing.is_some());
+        assert!(report.degradation(Degradation::Blur).is_none());
+    }
+
+    #[test]
+    fn test_sizes_tried_largest_first() {
+        let options = QRCodeStylingOptions {
+            data: "https://example.com/sizes".to_string(),
+            ..Default::default()
+        };
+        let matrix = QRMatrix::new(&options.data, &options.qr_options).unwrap();
+        let robustness_options = RobustnessOptions::new()
+            .with_module_pixel_sizes(vec![4.0, 8.0])
+            .with_degradations(vec![]);
+        let report = robustness(&options, &matrix, &robustness_options).unwrap();
+        assert_eq!(report.sizes[0].level, 8.0);
+    }
+
+    #[test]
+    fn test_modules_too_small() {
+        let options = QRCodeStylingOptions {
+            data: "https://example.com/tiny".to_string(),
+            width: 40,
+            height: 40,
+            qr_options: QROptions::new().with_type_number(40),
+            ..Default::default()
+        };
+        let matrix = QRMatrix::new(&options.data, &options.qr_options).unwrap();
+        assert!(matches!(
+            robustness(&options, &matrix, &RobustnessOptions::new()),
+            Err(QRError::CanvasTooSmall { .. })
+        ));
+    }
+
+    #[test]
+    fn test_min_print_size() {
+        let report = RobustnessReport {
+            module_count: 25,
+            sizes: Vec::new(),
+            min_module_pixels: Some(2.0),
+            degradations: Vec::new(),
+        };
+        assert!((report.min_print_size_mm(300.0).unwrap() - 4.2333).abs() < 1e-3);
+    }
+}
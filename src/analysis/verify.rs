@@ -0,0 +1,258 @@
+//! Round-trip decode verification of a rendered QR code.
+
+use image::GrayImage;
+
+use super::{luminance_threshold, rasterize, read_back};
+use crate::config::QRCodeStylingOptions;
+use crate::core::QRMatrix;
+use crate::error::Result;
+use crate::types::{ErrorCorrectionLevel, FinderCorner};
+
+/// Whether a finder pattern shows the 1:1:3:1:1 profile scanners look for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FinderStatus {
+    /// Which finder pattern.
+    pub corner: FinderCorner,
+    /// Found along both the horizontal and vertical center lines.
+    pub located: bool,
+}
+
+/// Outcome of [`QRCodeStyling::verify`](crate::QRCodeStyling::verify).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VerifyReport {
+    /// The decoder read a payload from the rendered image.
+    pub decoded: bool,
+    /// The detector could not locate the code on its own and the modules
+    /// were sampled at their known positions instead. Such codes are not
+    /// [`is_ok`](Self::is_ok): the modules are right, but a scanner has to
+    /// find them first. Round finder eyes and modules too small to form
+    /// the alignment pattern's center cause this.
+    pub grid_sampled: bool,
+    /// The decoded payload equals the input data.
+    pub data_matches: bool,
+    /// Decoded payload, if any.
+    pub decoded_data: Option<String>,
+    /// Version read back from the image.
+    pub version: Option<u8>,
+    /// Error correction level read back from the image.
+    pub error_correction_level: Option<ErrorCorrectionLevel>,
+    /// Mask pattern (0-7) read back from the image.
+    pub mask: Option<u8>,
+    /// Per-corner finder pattern detection.
+    pub finder_patterns: Vec<FinderStatus>,
+    /// Finder patterns drawn `Dot` or `ExtraRounded`, which the detector
+    /// can't fit the grid to at any size.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub round_finders: Vec<FinderCorner>,
+    /// Decoder error message when decoding failed.
+    pub error: Option<String>,
+}
+
+impl VerifyReport {
+    /// Returns true if the detector found the code on its own and it
+    /// decoded to exactly the input data.
+    pub fn is_ok(&self) -> bool {
+        self.decoded && self.data_matches && !self.grid_sampled
+    }
+
+    /// Returns true if the code decoded to the input data, possibly only by
+    /// sampling modules at their known positions. Such codes have the right
+    /// modules but may not be found by real scanners.
+    pub fn decodes_with_known_geometry(&self) -> bool {
+        self.decoded && self.data_matches
+    }
+
+    /// Corners whose finder pattern could not be located.
+    pub fn missing_finders(&self) -> Vec<FinderCorner> {
+        self.finder_patterns
+            .iter()
+            .filter(|f| !f.located)
+            .map(|f| f.corner)
+            .collect()
+    }
+}
+
+/// Rasterize the styled code and read it back.
+pub(crate) fn verify(options: &QRCodeStylingOptions, matrix: &QRMatrix) -> Result<VerifyReport> {
+    let (image, geometry) = rasterize(options, matrix, 1.0)?;
+    let count = geometry.count;
+
+    let finder_patterns = FinderCorner::ALL
+        .iter()
+        .map(|&corner| {
+            let (row, col) = corner.origin(count);
+            let cx = geometry.x + (col as f64 + 3.5) * geometry.dot_size;
+            let cy = geometry.y + (row as f64 + 3.5) * geometry.dot_size;
+            FinderStatus {
+                corner,
+                located: finder_located(&image, cx, cy, geometry.dot_size),
+            }
+        })
+        .collect();
+
+    let round_finders = FinderCorner::ALL
+        .iter()
+        .copied()
+        .filter(|&corner| {
+            let style = options.corners_options.corner(corner);
+            let square = style
+                .square
+                .as_ref()
+                .unwrap_or(&options.corners_square_options);
+            square.square_type.is_round()
+        })
+        .collect();
+
+    let (decoded, grid_sampled) = read_back(&image, Some(&geometry));
+
+    let report = match decoded {
+        Ok(decoded) => VerifyReport {
+            decoded: true,
+            grid_sampled,
+            data_matches: decoded.data == options.data,
+            decoded_data: Some(decoded.data),
+            version: Some(decoded.version),
+            error_correction_level: Some(decoded.error_correction_level),
+            mask: Some(decoded.mask),
+            finder_patterns,
+            round_finders,
+            error: None,
+        },
+        Err(error) => VerifyReport {
+            decoded: false,
+            grid_sampled,
+            data_matches: false,
+            decoded_data: None,
+            version: None,
+            error_correction_level: None,
+            mask: None,
+            finder_patterns,
+            round_finders,
+            error: Some(error),
+        },
+    };
+
+    Ok(report)
+}
+
+/// Check the dark-light-dark-light-dark profile through a finder center.
+fn finder_located(image: &GrayImage, cx: f64, cy: f64, module: f64) -> bool {
+    let Some(threshold) = luminance_threshold(image) else {
+        return false;
+    };
+    let is_dark = |x: i64, y: i64| -> bool {
+        if x < 0 || y < 0 || x >= image.width() as i64 || y >= image.height() as i64 {
+            return false;
+        }
+        (image.get_pixel(x as u32, y as u32).0[0] as u16) < threshold
+    };
+
+    let reach = (module * 5.5).ceil() as i64;
+    let (cx, cy) = (cx.floor() as i64, cy.floor() as i64);
+    let horizontal: Vec<bool> = (-reach..=reach).map(|d| is_dark(cx + d, cy)).collect();
+    let vertical: Vec<bool> = (-reach..=reach).map(|d| is_dark(cx, cy + d)).collect();
+
+    has_finder_profile(&horizontal, reach as usize) && has_finder_profile(&vertical, reach as usize)
+}
+
+/// True if the run containing `center` is a dark core flanked 1:1:3:1:1.
+fn has_finder_profile(samples: &[bool], center: usize) -> bool {
+    let mut runs: Vec<(bool, usize)> = Vec::new();
+    let mut center_run = 0;
+    for (i, &dark) in samples.iter().enumerate() {
+        match runs.last_mut() {
+            Some((color, len)) if *color == dark => *len += 1,
+            _ => runs.push((dark, 1)),
+        }
+        if i == center {
+            center_run = runs.len() - 1;
+        }
+    }
+
+    if center_run < 2 || center_run + 2 >= runs.len() || !runs[center_run].0 {
+        return false;
+    }
+
+    let lengths: Vec<f64> = runs[center_run - 2..=center_run + 2]
+        .iter()
+        .map(|&(_, len)| len as f64)
+        .collect();
+    let unit = lengths.iter().sum::<f64>() / 7.0;
+    let expected = [1.0, 1.0, 3.0, 1.0, 1.0];
+
+    lengths
+        .iter()
+        .zip(expected)
+        .all(|(len, e)| (len - e * unit).abs() <= unit * 0.75)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Color, CornersDotOptions, CornersSquareOptions, DotsOptions};
+    use crate::types::{CornerDotType, CornerSquareType, DotType};
+
+    #[test]
+    fn test_finder_profile() {
+        let run = |n: usize, dark: bool| vec![dark; n];
+        let samples: Vec<bool> = [
+            run(5, false),
+            run(4, true),
+            run(4, false),
+            run(12, true),
+            run(4, false),
+            run(4, true),
+            run(5, false),
+        ]
+        .concat();
+        assert!(has_finder_profile(&samples, 19));
+        // Center falling on a light run is not a finder.
+        assert!(!has_finder_profile(&samples, 11));
+    }
+
+    #[test]
+    fn test_solid_block_is_not_finder() {
+        let samples = vec![true; 40];
+        assert!(!has_finder_profile(&samples, 20));
+    }
+
+    #[test]
+    fn test_verify() {
+        let options = QRCodeStylingOptions {
+            data: "https://example.com".to_string(),
+            dots_options: DotsOptions::new(DotType::Rounded),
+            ..Default::default()
+        };
+        let matrix = QRMatrix::new(&options.data, &options.qr_options).unwrap();
+
+        let report = verify(&options, &matrix).unwrap();
+        assert!(report.is_ok(), "{:?}", report);
+        assert!(!report.grid_sampled);
+        assert_eq!(report.version, Some(matrix.version()));
+        assert_eq!(
+            report.error_correction_level,
+            Some(options.qr_options.error_correction_level)
+        );
+        assert!(report.missing_finders().is_empty());
+    }
+
+    #[test]
+    fn test_verify_low_contrast_fails() {
+        let pale = Color::rgb(250, 250, 250);
+        let options = QRCodeStylingOptions {
+            data: "https://example.com".to_string(),
+            dots_options: DotsOptions::new(DotType::Square).with_color(pale),
+            corners_square_options: CornersSquareOptions::new(CornerSquareType::Square)
+                .with_color(pale),
+            corners_dot_options: CornersDotOptions::new(CornerDotType::Square).with_color(pale),
+            ..Default::default()
+        };
+        let matrix = QRMatrix::new(&options.data, &options.qr_options).unwrap();
+
+        let report = verify(&options, &matrix).unwrap();
+        assert!(!report.is_ok());
+        assert!(report.error.is_some());
+    }
+}
@@ -0,0 +1,120 @@
+//! Alignment pattern styling options.
+
+use super::{Color, Gradient};
+use crate::types::{CornerDotType, CornerSquareType};
+
+/// Options for drawing each alignment pattern as one 5×5 unit, the way
+/// finder patterns are drawn, instead of module by module.
+///
+/// Takes precedence over `StructuralOptions::alignment`. Custom shape
+/// types have no artwork here and fail to build, and patterns partly
+/// hidden by a logo are still drawn module by module.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlignmentOptions {
+    /// Shape of the outer ring.
+    pub ring_type: CornerSquareType,
+    /// Shape of the center module.
+    pub center_type: CornerDotType,
+    /// Solid color (ignored if gradient is set).
+    pub color: Color,
+    /// Optional gradient, spanning the code like the dots' gradient.
+    pub gradient: Option<Gradient>,
+    /// Radius of tunable shapes, as for the corner options (0.0 to 1.0).
+    pub radius: f64,
+}
+
+impl Default for AlignmentOptions {
+    fn default() -> Self {
+        Self {
+            ring_type: CornerSquareType::Square,
+            center_type: CornerDotType::Square,
+            color: Color::BLACK,
+            gradient: None,
+            radius: 0.5,
+        }
+    }
+}
+
+impl AlignmentOptions {
+    /// Create alignment options with the given ring and center shapes.
+    pub fn new(ring_type: CornerSquareType, center_type: CornerDotType) -> Self {
+        Self {
+            ring_type,
+            center_type,
+            ..Default::default()
+        }
+    }
+
+    /// Set the ring shape.
+    pub fn with_ring_type(mut self, ring_type: CornerSquareType) -> Self {
+        self.ring_type = ring_type;
+        self
+    }
+
+    /// Set the center shape.
+    pub fn with_center_type(mut self, center_type: CornerDotType) -> Self {
+        self.center_type = center_type;
+        self
+    }
+
+    /// Set the color.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the gradient.
+    pub fn with_gradient(mut self, gradient: Gradient) -> Self {
+        self.gradient = Some(gradient);
+        self
+    }
+
+    /// Set the radius of tunable shapes (0.0 to 1.0).
+    pub fn with_radius(mut self, radius: f64) -> Self {
+        self.radius = radius.clamp(0.0, 1.0);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alignment_units_decode() {
+        use crate::config::{DotsOptions, QROptions};
+        use crate::error::QRError;
+        use crate::types::DotType;
+        use crate::QRCodeStyling;
+
+        let build = |alignment_options: AlignmentOptions| {
+            QRCodeStyling::builder()
+                .data("https://example.com/alignment/units")
+                .qr_options(QROptions::new().with_type_number(8))
+                .dots_options(DotsOptions::new(DotType::Classy))
+                .alignment_options(alignment_options)
+                .build()
+        };
+        let qr = build(AlignmentOptions::new(
+            CornerSquareType::Rounded,
+            CornerDotType::Dot,
+        ))
+        .unwrap();
+        let svg = qr.render_svg().unwrap();
+        let clip = svg.split("clip-path-alignment-units-").nth(1).unwrap();
+        let clip = &clip[..clip.find("</clipPath>").unwrap()];
+        // Version 8 has six alignment patterns, each a ring and a center.
+        assert_eq!(clip.matches("<path").count(), 6);
+        assert_eq!(clip.matches("<circle").count(), 6);
+        let report = qr.verify().unwrap();
+        assert!(report.is_ok(), "{:?}", report.error);
+
+        // Alignment patterns have nothing to draw custom shapes with.
+        let custom = build(AlignmentOptions::new(
+            CornerSquareType::Custom,
+            CornerDotType::Dot,
+        ));
+        assert!(matches!(custom, Err(QRError::InvalidOptions(_))));
+    }
+}
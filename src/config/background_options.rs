@@ -1,6 +1,7 @@
 //! Background styling options.
 
 use super::{Color, Gradient};
+use crate::types::ImageFit;
 
 /// Options for styling QR code background.
 #[derive(Debug, Clone, PartialEq)]
@@ -12,6 +13,8 @@ pub struct BackgroundOptions {
     pub gradient: Option<Gradient>,
     /// Corner radius ratio (0.0 to 1.0, where 0.5 = fully rounded).
     pub round: f64,
+    /// Optional image drawn over the color or gradient.
+    pub image: Option<BackgroundImage>,
 }
 
 impl Default for BackgroundOptions {
@@ -20,6 +23,7 @@ impl Default for BackgroundOptions {
             color: Color::WHITE,
             gradient: None,
             round: 0.0,
+            image: None,
         }
     }
 }
@@ -39,6 +43,7 @@ impl BackgroundOptions {
             color: Color::TRANSPARENT,
             gradient: None,
             round: 0.0,
+            image: None,
         }
     }
 
@@ -59,4 +64,138 @@ impl BackgroundOptions {
         self.round = round.clamp(0.0, 0.5);
         self
     }
+
+    /// Set the background image.
+    pub fn with_image(mut self, image: BackgroundImage) -> Self {
+        self.image = Some(image);
+        self
+    }
+}
+
+/// Picture drawn behind the code, such as an event photo on a ticket.
+///
+/// A translucent panel in the background color is laid over the picture
+/// behind the code area so modules stay readable. Contrast checks only see
+/// the background color; use [`verify`](crate::QRCodeStyling::verify) to
+/// check codes over busy pictures.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BackgroundImage {
+    /// Image data (PNG, JPEG, WebP or SVG).
+    pub image: Vec<u8>,
+    /// How the image fills the background.
+    pub fit: ImageFit,
+    /// Image opacity (0.0 to 1.0).
+    pub opacity: f64,
+    /// Gaussian blur standard deviation in pixels (0.0 for none).
+    pub blur: f64,
+    /// How much color to remove (0.0 keeps it, 1.0 is grayscale).
+    pub desaturate: f64,
+    /// Opacity of the readability panel behind the code area, if any.
+    pub overlay: Option<f64>,
+}
+
+impl BackgroundImage {
+    /// Cover the background with an image, under a readability panel.
+    pub fn new(image: Vec<u8>) -> Self {
+        Self {
+            image,
+            fit: ImageFit::Cover,
+            opacity: 1.0,
+            blur: 0.0,
+            desaturate: 0.0,
+            overlay: Some(0.75),
+        }
+    }
+
+    /// Set the fit mode.
+    pub fn with_fit(mut self, fit: ImageFit) -> Self {
+        self.fit = fit;
+        self
+    }
+
+    /// Set the image opacity (0.0 to 1.0).
+    pub fn with_opacity(mut self, opacity: f64) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Blur the image by a standard deviation in pixels.
+    pub fn with_blur(mut self, blur: f64) -> Self {
+        self.blur = blur.max(0.0);
+        self
+    }
+
+    /// Remove some or all of the image's color (0.0 to 1.0).
+    pub fn with_desaturate(mut self, amount: f64) -> Self {
+        self.desaturate = amount.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the readability panel opacity (0.0 to 1.0).
+    pub fn with_overlay(mut self, opacity: f64) -> Self {
+        self.overlay = Some(opacity.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Draw the image without a readability panel.
+    pub fn without_overlay(mut self) -> Self {
+        self.overlay = None;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_background_image() {
+        use crate::types::OutputFormat;
+        use crate::QRCodeStyling;
+
+        let picture = image::RgbImage::from_fn(60, 40, |x, y| {
+            image::Rgb([(x * 4) as u8, 90, (y * 6) as u8])
+        });
+        let mut png = Vec::new();
+        image::DynamicImage::ImageRgb8(picture)
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .unwrap();
+        let build = |image: BackgroundImage| {
+            QRCodeStyling::builder()
+                .data("https://example.com/ticket")
+                .background_options(BackgroundOptions::new(Color::WHITE).with_image(image))
+                .build()
+                .unwrap()
+        };
+
+        let qr = build(
+            BackgroundImage::new(png.clone())
+                .with_blur(2.0)
+                .with_desaturate(0.5),
+        );
+        let svg = qr.render_svg().unwrap();
+        assert!(svg.contains("preserveAspectRatio=\"xMidYMid slice\""));
+        assert!(svg.contains("<feColorMatrix type=\"saturate\" values=\"0.5\"/>"));
+        assert!(svg.contains("<feGaussianBlur stdDeviation=\"2\"/>"));
+        // The readability panel is drawn in the background color.
+        assert!(svg.contains("fill=\"#FFFFFF\" fill-opacity=\"0.75\""));
+        let report = qr.verify().unwrap();
+        assert!(report.is_ok(), "{:?}", report.error);
+
+        // Tiles repeat at the image's own size.
+        let qr = build(
+            BackgroundImage::new(png.clone())
+                .with_fit(ImageFit::Tile)
+                .with_opacity(0.5),
+        );
+        let svg = qr.render_svg().unwrap();
+        assert!(svg.contains("width=\"60\" height=\"40\" preserveAspectRatio=\"none\""));
+        assert!(svg.contains("<g opacity=\"0.5\">"));
+        assert!(qr.render(OutputFormat::Pdf).is_ok());
+        let svg = build(BackgroundImage::new(png).without_overlay())
+            .render_svg()
+            .unwrap();
+        assert!(!svg.contains("fill-opacity=\"0.75\""));
+    }
 }
@@ -1,10 +1,15 @@
 //! Color representation for QR code styling.
 
+use super::css_color;
 use crate::error::{QRError, Result};
 
 /// RGBA color representation.
+///
+/// Parses from CSS color strings with [`str::parse`]; with the `serde`
+/// feature it deserializes from either a CSS string or its components.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "ColorValue"))]
 pub struct Color {
     /// Red component (0-255).
     pub r: u8,
@@ -27,7 +32,7 @@ impl Color {
         Self { r, g, b, a }
     }
 
-    /// Create a color from a hex string (e.g., "#FF0000" or "#FF0000FF").
+    /// Create a color from a hex string (e.g., "#F00", "#FF0000" or "#FF0000FF").
     pub fn from_hex(hex: &str) -> Result<Self> {
         let hex = hex.trim_start_matches('#');
 
@@ -42,6 +47,19 @@ impl Color {
                     .map_err(|_| QRError::InvalidColor(hex.to_string()))?;
                 Ok(Self::rgb(r * 17, g * 17, b * 17))
             }
+            4 => {
+                // Short form with alpha: #RGBA -> #RRGGBBAA
+                let digit = |i: usize| {
+                    u8::from_str_radix(&hex[i..i + 1], 16)
+                        .map_err(|_| QRError::InvalidColor(hex.to_string()))
+                };
+                Ok(Self::rgba(
+                    digit(0)? * 17,
+                    digit(1)? * 17,
+                    digit(2)? * 17,
+                    digit(3)? * 17,
+                ))
+            }
             6 => {
                 // Standard form: #RRGGBB
                 let r = u8::from_str_radix(&hex[0..2], 16)
@@ -92,6 +110,167 @@ impl Color {
         }
     }
 
+    /// WCAG relative luminance (0.0 for black to 1.0 for white), ignoring alpha.
+    pub fn relative_luminance(&self) -> f64 {
+        let channel = |c: u8| {
+            let c = c as f64 / 255.0;
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * channel(self.r) + 0.7152 * channel(self.g) + 0.0722 * channel(self.b)
+    }
+
+    /// WCAG contrast ratio between two colors (1.0 to 21.0).
+    pub fn contrast_ratio(&self, other: &Color) -> f64 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Composite this color over an opaque `background`.
+    pub fn over(&self, background: &Color) -> Color {
+        let alpha = self.a as f64 / 255.0;
+        let blend = |fg: u8, bg: u8| (fg as f64 * alpha + bg as f64 * (1.0 - alpha)).round() as u8;
+        Color::rgb(
+            blend(self.r, background.r),
+            blend(self.g, background.g),
+            blend(self.b, background.b),
+        )
+    }
+
+    /// Linearly interpolate towards `other` by `t` (0.0 to 1.0), keeping alpha.
+    pub fn mix(&self, other: &Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let lerp = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Color::rgba(
+            lerp(self.r, other.r),
+            lerp(self.g, other.g),
+            lerp(self.b, other.b),
+            self.a,
+        )
+    }
+
+    /// Create a color from HSL: hue in degrees, saturation and lightness
+    /// from 0.0 to 1.0.
+    pub fn from_hsl(hue: f64, saturation: f64, lightness: f64) -> Self {
+        let (s, l) = (saturation.clamp(0.0, 1.0), lightness.clamp(0.0, 1.0));
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let (r, g, b) = hue_rgb(hue, chroma);
+        let m = l - chroma / 2.0;
+        Self::from_unit(r + m, g + m, b + m)
+    }
+
+    /// Hue in degrees, saturation and lightness (0.0 to 1.0), ignoring alpha.
+    pub fn to_hsl(&self) -> (f64, f64, f64) {
+        let (r, g, b) = (
+            self.r as f64 / 255.0,
+            self.g as f64 / 255.0,
+            self.b as f64 / 255.0,
+        );
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let chroma = max - min;
+        let lightness = (max + min) / 2.0;
+        if chroma == 0.0 {
+            return (0.0, 0.0, lightness);
+        }
+        let hue = if max == r {
+            60.0 * ((g - b) / chroma).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / chroma + 2.0)
+        } else {
+            60.0 * ((r - g) / chroma + 4.0)
+        };
+        let saturation = chroma / (1.0 - (2.0 * lightness - 1.0).abs());
+        (hue, saturation, lightness)
+    }
+
+    /// Create a color from HWB: hue in degrees, whiteness and blackness from
+    /// 0.0 to 1.0.
+    pub fn from_hwb(hue: f64, whiteness: f64, blackness: f64) -> Self {
+        let (w, b) = (whiteness.clamp(0.0, 1.0), blackness.clamp(0.0, 1.0));
+        if w + b >= 1.0 {
+            let gray = w / (w + b);
+            return Self::from_unit(gray, gray, gray);
+        }
+        let (r, g, bl) = hue_rgb(hue, 1.0);
+        let scale = 1.0 - w - b;
+        Self::from_unit(r * scale + w, g * scale + w, bl * scale + w)
+    }
+
+    /// Create a color from OKLCH: lightness from 0.0 to 1.0, chroma (about
+    /// 0.0 to 0.4) and hue in degrees. Colors outside sRGB are clipped.
+    pub fn from_oklch(lightness: f64, chroma: f64, hue: f64) -> Self {
+        let (sin, cos) = hue.to_radians().sin_cos();
+        let (a, b) = (chroma.max(0.0) * cos, chroma.max(0.0) * sin);
+        let l = lightness + 0.3963377774 * a + 0.2158037573 * b;
+        let m = lightness - 0.1055613458 * a - 0.0638541728 * b;
+        let s = lightness - 0.0894841775 * a - 1.2914855480 * b;
+        let (l, m, s) = (l.powi(3), m.powi(3), s.powi(3));
+
+        let encode = |c: f64| {
+            let c = c.clamp(0.0, 1.0);
+            if c <= 0.0031308 {
+                12.92 * c
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        };
+        Self::from_unit(
+            encode(4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s),
+            encode(-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s),
+            encode(-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s),
+        )
+    }
+
+    /// OKLCH lightness, chroma and hue in degrees, ignoring alpha.
+    pub fn to_oklch(&self) -> (f64, f64, f64) {
+        let decode = |c: u8| {
+            let c = c as f64 / 255.0;
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        let (r, g, b) = (decode(self.r), decode(self.g), decode(self.b));
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+        let lightness = 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s;
+        let a = 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s;
+        let b = 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s;
+        (
+            lightness,
+            a.hypot(b),
+            b.atan2(a).to_degrees().rem_euclid(360.0),
+        )
+    }
+
+    /// Raise the HSL lightness by `amount` (0.0 to 1.0), keeping alpha.
+    pub fn lighten(&self, amount: f64) -> Color {
+        let (h, s, l) = self.to_hsl();
+        Color {
+            a: self.a,
+            ..Color::from_hsl(h, s, l + amount)
+        }
+    }
+
+    /// Lower the HSL lightness by `amount` (0.0 to 1.0), keeping alpha.
+    pub fn darken(&self, amount: f64) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Opaque color from channels in 0.0 to 1.0.
+    fn from_unit(r: f64, g: f64, b: f64) -> Self {
+        let channel = |c: f64| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Self::rgb(channel(r), channel(g), channel(b))
+    }
+
     /// Black color.
     pub const BLACK: Color = Color::rgb(0, 0, 0);
 
@@ -108,6 +287,52 @@ impl Default for Color {
     }
 }
 
+/// Red, green and blue (0.0 to `chroma`) of a fully saturated `hue` in
+/// degrees.
+fn hue_rgb(hue: f64, chroma: f64) -> (f64, f64, f64) {
+    let h = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    }
+}
+
+impl std::str::FromStr for Color {
+    type Err = QRError;
+
+    /// Parse a CSS color: hex, a named color, or an `rgb()`, `rgba()`,
+    /// `hsl()`, `hsla()`, `hwb()` or `oklch()` function.
+    fn from_str(s: &str) -> Result<Self> {
+        css_color::parse(s).ok_or_else(|| QRError::InvalidColor(s.to_string()))
+    }
+}
+
+/// A color as deserialized: a CSS color string or RGBA components.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum ColorValue {
+    Css(String),
+    Rgba { r: u8, g: u8, b: u8, a: u8 },
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ColorValue> for Color {
+    type Error = QRError;
+
+    fn try_from(value: ColorValue) -> Result<Self> {
+        match value {
+            ColorValue::Css(css) => css.parse(),
+            ColorValue::Rgba { r, g, b, a } => Ok(Color::rgba(r, g, b, a)),
+        }
+    }
+}
+
 impl std::fmt::Display for Color {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.to_hex())
@@ -127,6 +352,64 @@ mod tests {
         assert_eq!(Color::from_hex("#FFF").unwrap(), Color::rgb(255, 255, 255));
     }
 
+    #[test]
+    fn test_contrast_ratio() {
+        assert!((Color::BLACK.contrast_ratio(&Color::WHITE) - 21.0).abs() < 1e-9);
+        assert!((Color::WHITE.contrast_ratio(&Color::WHITE) - 1.0).abs() < 1e-9);
+        let gray = Color::rgb(119, 119, 119);
+        assert!((gray.contrast_ratio(&Color::WHITE) - 4.48).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_over_and_mix() {
+        let half_black = Color::rgba(0, 0, 0, 128);
+        assert_eq!(half_black.over(&Color::WHITE), Color::rgb(127, 127, 127));
+        assert_eq!(
+            Color::WHITE.mix(&Color::BLACK, 0.5),
+            Color::rgb(128, 128, 128)
+        );
+    }
+
+    #[test]
+    fn test_parse_css() {
+        let color: Color = "hsla(210, 50%, 40%, 0.25)".parse().unwrap();
+        assert_eq!(color, Color::rgba(51, 102, 153, 64));
+        assert_eq!(
+            "#0F08".parse::<Color>().unwrap(),
+            Color::rgba(0, 255, 0, 136)
+        );
+        assert!(matches!(
+            "nope".parse::<Color>(),
+            Err(QRError::InvalidColor(_))
+        ));
+    }
+
+    #[test]
+    fn test_hsl_oklch_round_trip() {
+        let color = Color::rgb(51, 102, 153);
+        let (h, s, l) = color.to_hsl();
+        assert_eq!(Color::from_hsl(h, s, l), color);
+        let (l, c, h) = color.to_oklch();
+        assert_eq!(Color::from_oklch(l, c, h), color);
+    }
+
+    #[test]
+    fn test_lighten_darken() {
+        let color = Color::rgba(51, 102, 153, 200);
+        assert_eq!(color.lighten(0.2), Color::rgba(102, 153, 204, 200));
+        assert_eq!(color.darken(1.0), Color::rgba(0, 0, 0, 200));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_css_or_components() {
+        let css: Color = serde_json::from_str("\"rgb(0 128 0 / 50%)\"").unwrap();
+        assert_eq!(css, Color::rgba(0, 128, 0, 128));
+        let json = serde_json::to_string(&css).unwrap();
+        assert_eq!(serde_json::from_str::<Color>(&json).unwrap(), css);
+        assert!(serde_json::from_str::<Color>("\"nope\"").is_err());
+    }
+
     #[test]
     fn test_to_hex() {
         assert_eq!(Color::rgb(255, 0, 0).to_hex(), "#FF0000");
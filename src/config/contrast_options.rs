@@ -0,0 +1,73 @@
+//! Color contrast validation options.
+
+/// What to do when a foreground fill does not contrast enough with the background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ContrastPolicy {
+    /// Do not check (default).
+    #[default]
+    Ignore,
+    /// Build anyway and keep the failing checks in
+    /// [`QRCodeStyling::contrast_warnings`](crate::QRCodeStyling::contrast_warnings).
+    Warn,
+    /// Fail with [`QRError::InsufficientContrast`](crate::QRError::InsufficientContrast).
+    Error,
+    /// Darken foreground stops (or lighten background stops) until the
+    /// threshold is met.
+    AutoAdjust,
+}
+
+/// Options for foreground/background contrast checking.
+///
+/// Only solid colors, gradient stops and module palettes are checked.
+/// Background images, pattern fills and the picture pixels of a halftone
+/// code are not, so run `verify()` on codes that use them.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContrastOptions {
+    /// Enforcement policy applied when the QR code is built.
+    pub policy: ContrastPolicy,
+    /// Minimum WCAG contrast ratio (1.0 to 21.0).
+    pub min_ratio: f64,
+    /// Accept light modules on a dark background.
+    pub allow_inverted: bool,
+}
+
+impl Default for ContrastOptions {
+    fn default() -> Self {
+        Self {
+            policy: ContrastPolicy::Ignore,
+            min_ratio: 4.5,
+            allow_inverted: false,
+        }
+    }
+}
+
+impl ContrastOptions {
+    /// Create contrast options with the given policy.
+    pub fn new(policy: ContrastPolicy) -> Self {
+        Self {
+            policy,
+            ..Default::default()
+        }
+    }
+
+    /// Set the policy.
+    pub fn with_policy(mut self, policy: ContrastPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Set the minimum contrast ratio.
+    pub fn with_min_ratio(mut self, min_ratio: f64) -> Self {
+        self.min_ratio = min_ratio.clamp(1.0, 21.0);
+        self
+    }
+
+    /// Set whether inverted polarity is accepted.
+    pub fn with_allow_inverted(mut self, allow: bool) -> Self {
+        self.allow_inverted = allow;
+        self
+    }
+}
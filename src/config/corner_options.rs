@@ -1,7 +1,20 @@
 //! Corner styling options.
 
-use super::{Color, Gradient};
-use crate::types::{CornerSquareType, CornerDotType};
+use super::{Color, Gradient, OutlineOptions, PatternFill};
+use crate::types::{CornerDotType, CornerOrientation, CornerSquareType, FinderCorner};
+
+/// User-supplied finder pattern artwork.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum CornerArtwork {
+    /// SVG path data in a box of 7×7 units for squares or 3×3 for dots,
+    /// filled with the even-odd rule in the options' color.
+    Path(String),
+    /// Raster (PNG, JPEG, WebP) or SVG image stretched over the area; the
+    /// options' color and gradient are not used.
+    Image(Vec<u8>),
+}
 
 /// Options for styling QR code corner squares (finder patterns).
 #[derive(Debug, Clone, PartialEq)]
@@ -13,6 +26,23 @@ pub struct CornersSquareOptions {
     pub color: Color,
     /// Optional gradient for corner squares.
     pub gradient: Option<Gradient>,
+    /// Pattern or texture fill (overrides color and gradient).
+    pub pattern: Option<PatternFill>,
+    /// Artwork used when the type is [`CornerSquareType::Custom`].
+    pub artwork: Option<CornerArtwork>,
+    /// Corner radius of rounded and leaf shapes, or corner cut of the
+    /// octagon, as a fraction of half the side (0.0 to 1.0). Finder
+    /// patterns cap it at [`CornerSquareType::max_radius`].
+    #[cfg_attr(feature = "serde", serde(default = "default_radius"))]
+    pub radius: f64,
+    /// Draw the square as an outline (not applied to artwork).
+    pub outline: Option<OutlineOptions>,
+}
+
+/// Radius of configs saved before corner shapes had one.
+#[cfg(feature = "serde")]
+fn default_radius() -> f64 {
+    0.5
 }
 
 impl Default for CornersSquareOptions {
@@ -21,6 +51,10 @@ impl Default for CornersSquareOptions {
             square_type: CornerSquareType::Square,
             color: Color::BLACK,
             gradient: None,
+            pattern: None,
+            radius: 0.5,
+            artwork: None,
+            outline: None,
         }
     }
 }
@@ -51,6 +85,39 @@ impl CornersSquareOptions {
         self.gradient = Some(gradient);
         self
     }
+
+    /// Fill with a pattern or texture.
+    pub fn with_pattern(mut self, pattern: PatternFill) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
+    /// Set the radius of tunable shapes (0.0 to 1.0).
+    pub fn with_radius(mut self, radius: f64) -> Self {
+        self.radius = radius.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Draw as an outline.
+    pub fn with_outline(mut self, outline: OutlineOptions) -> Self {
+        self.outline = Some(outline);
+        self
+    }
+
+    /// Draw from SVG path data in a 7×7 unit box (sets the type to
+    /// [`CornerSquareType::Custom`]).
+    pub fn with_path(mut self, d: impl Into<String>) -> Self {
+        self.square_type = CornerSquareType::Custom;
+        self.artwork = Some(CornerArtwork::Path(d.into()));
+        self
+    }
+
+    /// Draw a raster or SVG image (sets the type to [`CornerSquareType::Custom`]).
+    pub fn with_image(mut self, image: Vec<u8>) -> Self {
+        self.square_type = CornerSquareType::Custom;
+        self.artwork = Some(CornerArtwork::Image(image));
+        self
+    }
 }
 
 /// Options for styling QR code corner dots (center of finder patterns).
@@ -63,6 +130,17 @@ pub struct CornersDotOptions {
     pub color: Color,
     /// Optional gradient for corner dots.
     pub gradient: Option<Gradient>,
+    /// Pattern or texture fill (overrides color and gradient).
+    pub pattern: Option<PatternFill>,
+    /// Artwork used when the type is [`CornerDotType::Custom`].
+    pub artwork: Option<CornerArtwork>,
+    /// Corner radius of rounded and leaf shapes as a fraction of half the
+    /// side, or how pinched the star is (0.0 to 1.0). Capped at
+    /// [`CornerDotType::max_radius`].
+    #[cfg_attr(feature = "serde", serde(default = "default_radius"))]
+    pub radius: f64,
+    /// Draw the dot as an outline (not applied to artwork).
+    pub outline: Option<OutlineOptions>,
 }
 
 impl Default for CornersDotOptions {
@@ -71,6 +149,10 @@ impl Default for CornersDotOptions {
             dot_type: CornerDotType::Dot,
             color: Color::BLACK,
             gradient: None,
+            pattern: None,
+            radius: 0.5,
+            artwork: None,
+            outline: None,
         }
     }
 }
@@ -101,4 +183,233 @@ impl CornersDotOptions {
         self.gradient = Some(gradient);
         self
     }
+
+    /// Fill with a pattern or texture.
+    pub fn with_pattern(mut self, pattern: PatternFill) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
+    /// Set the radius of tunable shapes (0.0 to 1.0).
+    pub fn with_radius(mut self, radius: f64) -> Self {
+        self.radius = radius.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Draw as an outline.
+    pub fn with_outline(mut self, outline: OutlineOptions) -> Self {
+        self.outline = Some(outline);
+        self
+    }
+
+    /// Draw from SVG path data in a 3×3 unit box (sets the type to
+    /// [`CornerDotType::Custom`]).
+    pub fn with_path(mut self, d: impl Into<String>) -> Self {
+        self.dot_type = CornerDotType::Custom;
+        self.artwork = Some(CornerArtwork::Path(d.into()));
+        self
+    }
+
+    /// Draw a raster or SVG image (sets the type to [`CornerDotType::Custom`]).
+    pub fn with_image(mut self, image: Vec<u8>) -> Self {
+        self.dot_type = CornerDotType::Custom;
+        self.artwork = Some(CornerArtwork::Image(image));
+        self
+    }
+}
+
+/// Overrides for a single finder pattern; unset parts use the shared
+/// corner options.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CornerStyle {
+    /// Outer square in place of `CornersSquareOptions`.
+    pub square: Option<CornersSquareOptions>,
+    /// Inner dot in place of `CornersDotOptions`.
+    pub dot: Option<CornersDotOptions>,
+}
+
+impl CornerStyle {
+    /// Create a style with no overrides.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the outer square.
+    pub fn with_square(mut self, square: CornersSquareOptions) -> Self {
+        self.square = Some(square);
+        self
+    }
+
+    /// Override the inner dot.
+    pub fn with_dot(mut self, dot: CornersDotOptions) -> Self {
+        self.dot = Some(dot);
+        self
+    }
+}
+
+/// Orientation and per-corner overrides for the three finder patterns.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CornersOptions {
+    /// How the top-right and bottom-left patterns are turned.
+    pub orientation: CornerOrientation,
+    /// Top-left finder pattern overrides.
+    pub top_left: CornerStyle,
+    /// Top-right finder pattern overrides.
+    pub top_right: CornerStyle,
+    /// Bottom-left finder pattern overrides.
+    pub bottom_left: CornerStyle,
+}
+
+impl CornersOptions {
+    /// Create options with the default orientation and no overrides.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the orientation mode.
+    pub fn with_orientation(mut self, orientation: CornerOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the overrides for one finder pattern.
+    pub fn with_corner(mut self, corner: FinderCorner, style: CornerStyle) -> Self {
+        *self.corner_mut(corner) = style;
+        self
+    }
+
+    /// Overrides for `corner`.
+    pub fn corner(&self, corner: FinderCorner) -> &CornerStyle {
+        match corner {
+            FinderCorner::TopLeft => &self.top_left,
+            FinderCorner::TopRight => &self.top_right,
+            FinderCorner::BottomLeft => &self.bottom_left,
+        }
+    }
+
+    /// Mutable overrides for `corner`.
+    pub fn corner_mut(&mut self, corner: FinderCorner) -> &mut CornerStyle {
+        match corner {
+            FinderCorner::TopLeft => &mut self.top_left,
+            FinderCorner::TopRight => &mut self.top_right,
+            FinderCorner::BottomLeft => &mut self.bottom_left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corner_overrides_decode() {
+        use crate::QRCodeStyling;
+
+        let qr = QRCodeStyling::builder()
+            .data("https://example.com/corners")
+            .corners_square_options(
+                CornersSquareOptions::new(CornerSquareType::Leaf).with_radius(0.3),
+            )
+            .corners_options(
+                CornersOptions::new()
+                    .with_orientation(CornerOrientation::Mirrored)
+                    .with_corner(
+                        FinderCorner::TopRight,
+                        CornerStyle::new().with_square(
+                            CornersSquareOptions::new(CornerSquareType::Square)
+                                .with_color(Color::rgb(0, 90, 0)),
+                        ),
+                    ),
+            )
+            .build()
+            .unwrap();
+        let svg = qr.render_svg().unwrap();
+        assert!(svg.contains("fill=\"#005A00\""));
+        assert!(svg.contains("matrix(-1,0,0,1,"));
+        assert!(svg.contains("matrix(1,0,0,-1,0,"));
+        let report = qr.verify().unwrap();
+        assert!(report.is_ok(), "{:?}", report.error);
+    }
+
+    #[test]
+    fn test_mirrored_corner_gradient() {
+        use crate::config::ColorStop;
+        use crate::types::OutputFormat;
+        use crate::QRCodeStyling;
+
+        let qr = QRCodeStyling::builder()
+            .data("https://example.com/mirror")
+            .size(300)
+            .margin(0)
+            .corners_square_options(CornersSquareOptions::default().with_gradient(
+                Gradient::linear(vec![
+                    ColorStop::new(0.0, Color::rgb(255, 0, 0)),
+                    ColorStop::new(1.0, Color::rgb(0, 0, 255)),
+                ]),
+            ))
+            .corners_options(CornersOptions::new().with_orientation(CornerOrientation::Mirrored))
+            .build()
+            .unwrap();
+        let png = qr.render(OutputFormat::Png).unwrap();
+        let image = image::load_from_memory(&png).unwrap().to_rgb8();
+
+        // Along the top edge, the top-left eye runs red to blue and the
+        // mirrored top-right eye runs blue to red.
+        let white = |p: &[u8; 3]| p == &[255, 255, 255];
+        let top = (0..image.height())
+            .find(|&y| (0..image.width()).any(|x| !white(&image.get_pixel(x, y).0)))
+            .unwrap();
+        let row: Vec<_> = (0..image.width())
+            .map(|x| image.get_pixel(x, top + 2).0)
+            .filter(|p| !white(p))
+            .collect();
+        let (first, last) = (row[1], row[row.len() - 2]);
+        assert!(first[0] > 200 && first[2] < 50, "{:?}", first);
+        assert!(last[0] > 200 && last[2] < 50, "{:?}", last);
+    }
+
+    #[test]
+    fn test_corner_artwork() {
+        use crate::types::OutputFormat;
+        use crate::QRCodeStyling;
+
+        // A notched ring and a plus sign, drawn in unit boxes.
+        let ring = "M 0 0 H 6.5 L 7 0.5 V 7 H 0 Z M 1 1 V 6 H 6 V 1 Z";
+        let plus = "M 1 0 H 2 V 1 H 3 V 2 H 2 V 3 H 1 V 2 H 0 V 1 H 1 Z";
+        let qr = QRCodeStyling::builder()
+            .data("https://example.com/artwork")
+            .corners_square_options(CornersSquareOptions::default().with_path(ring))
+            .corners_dot_options(CornersDotOptions::default().with_path(plus))
+            .build()
+            .unwrap();
+        let svg = qr.render_svg().unwrap();
+        assert!(svg.contains(&format!("<path d=\"{}\" clip-rule=\"evenodd\"", ring)));
+        let report = qr.verify().unwrap();
+        assert!(report.is_ok(), "{:?}", report.error);
+        assert!(report.missing_finders().is_empty());
+
+        // Path data is escaped like any other attribute value.
+        let qr = QRCodeStyling::builder()
+            .data("https://example.com/artwork")
+            .corners_square_options(CornersSquareOptions::default().with_path("M 0 0\"/><x y=\""))
+            .build()
+            .unwrap();
+        assert!(qr
+            .render_svg()
+            .unwrap()
+            .contains("d=\"M 0 0&quot;/&gt;&lt;x y=&quot;\""));
+
+        let eye = b"<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 7 7\"><path d=\"M0 0h7v7H0z\"/></svg>";
+        let qr = QRCodeStyling::builder()
+            .data("https://example.com/artwork")
+            .corners_square_options(CornersSquareOptions::default().with_image(eye.to_vec()))
+            .build()
+            .unwrap();
+        let svg = qr.render_svg().unwrap();
+        assert_eq!(svg.matches("href=\"data:image/svg+xml;base64,").count(), 3);
+        assert!(!svg.contains("xlink:href=\"data:image/svg+xml"));
+        assert!(!qr.render(OutputFormat::Png).unwrap().is_empty());
+    }
 }
@@ -1,6 +1,12 @@
 //! Dot styling options.
 
-use super::{Color, Gradient};
+use std::f64::consts::PI;
+
+use super::{
+    Color, Gradient, ModuleColorFn, ModuleColors, ModuleInfo, OutlineOptions, PatternFill,
+};
+use crate::figures::dot::{DotJitter, MIN_MODULE_SCALE};
+use crate::figures::traits::{CustomDotDrawer, DotDrawer};
 use crate::types::DotType;
 
 /// Options for styling QR code dots.
@@ -13,8 +19,46 @@ pub struct DotsOptions {
     pub color: Color,
     /// Optional gradient for dots.
     pub gradient: Option<Gradient>,
+    /// Pattern or texture fill (overrides color and gradient).
+    pub pattern: Option<PatternFill>,
     /// Whether to round dot sizes to whole pixels.
     pub round_size: bool,
+    /// Palette painted module by module (overrides color and gradient).
+    pub module_colors: Option<ModuleColors>,
+    /// Per-module color function (overrides `module_colors`).
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub module_color_fn: Option<ModuleColorFn>,
+    /// Drawer used when `dot_type` is [`DotType::Custom`].
+    ///
+    /// Not serialized: set it again after deserializing, or building fails.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub custom_drawer: Option<CustomDotDrawer>,
+    /// Module size as a fraction of its cell; below 1.0 leaves gaps.
+    ///
+    /// This and the jitter settings are ignored by [`DotType::Liquid`].
+    #[cfg_attr(feature = "serde", serde(default = "unit_scale"))]
+    pub scale: f64,
+    /// Maximum relative random change of each module's size.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub size_jitter: f64,
+    /// Maximum random offset of each module, as a fraction of its cell.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub position_jitter: f64,
+    /// Maximum random rotation of each module in radians, either way.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rotation_jitter: f64,
+    /// Seed for the random module variation.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub seed: u64,
+    /// Draw the dots as outlines; touching square modules are outlined as
+    /// one region.
+    pub outline: Option<OutlineOptions>,
+}
+
+/// Module scale of configs saved before `scale` existed.
+#[cfg(feature = "serde")]
+fn unit_scale() -> f64 {
+    1.0
 }
 
 impl Default for DotsOptions {
@@ -23,7 +67,17 @@ impl Default for DotsOptions {
             dot_type: DotType::Square,
             color: Color::BLACK,
             gradient: None,
+            pattern: None,
             round_size: true,
+            module_colors: None,
+            module_color_fn: None,
+            custom_drawer: None,
+            scale: 1.0,
+            size_jitter: 0.0,
+            position_jitter: 0.0,
+            rotation_jitter: 0.0,
+            seed: 0,
+            outline: None,
         }
     }
 }
@@ -55,9 +109,115 @@ impl DotsOptions {
         self
     }
 
+    /// Fill with a pattern or texture.
+    pub fn with_pattern(mut self, pattern: PatternFill) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
     /// Set round_size option.
     pub fn with_round_size(mut self, round_size: bool) -> Self {
         self.round_size = round_size;
         self
     }
+
+    /// Color modules from a palette.
+    pub fn with_module_colors(mut self, colors: ModuleColors) -> Self {
+        self.module_colors = Some(colors);
+        self
+    }
+
+    /// Color each module with `f(row, col, info)`.
+    ///
+    /// Only modules of the symbol are passed to `f`. Decorative cells
+    /// outside it (circle shape) use the palette or `color` instead.
+    pub fn with_module_color_fn<F>(mut self, f: F) -> Self
+    where
+        F: Fn(usize, usize, ModuleInfo) -> Color + Send + Sync + 'static,
+    {
+        self.module_color_fn = Some(ModuleColorFn::new(f));
+        self
+    }
+
+    /// Returns true if dots are colored module by module.
+    pub fn has_module_colors(&self) -> bool {
+        self.module_color_fn.is_some() || self.module_colors.is_some()
+    }
+
+    /// Color of the module at `row`/`col`, falling back to `color`.
+    pub fn module_color(&self, row: usize, col: usize, info: ModuleInfo) -> Color {
+        if let Some(f) = self
+            .module_color_fn
+            .as_ref()
+            .filter(|_| info.kind.is_some())
+        {
+            return f.color(row, col, info);
+        }
+        self.module_colors
+            .as_ref()
+            .and_then(|colors| colors.color(row, col, &info))
+            .unwrap_or(self.color)
+    }
+
+    /// Draw dots with a custom drawer (sets the type to [`DotType::Custom`]).
+    pub fn with_custom_drawer(mut self, drawer: impl DotDrawer + 'static) -> Self {
+        self.dot_type = DotType::Custom;
+        self.custom_drawer = Some(CustomDotDrawer::new(drawer));
+        self
+    }
+
+    /// Set the module scale (clamped to 0.5..=1.0 so module centers stay dark).
+    ///
+    /// Shapes that don't fill their cell, such as stars and diamonds, are
+    /// kept larger when drawn; see
+    /// [`shape_coverage`](crate::figures::dot::shape_coverage).
+    pub fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = scale.clamp(MIN_MODULE_SCALE, 1.0);
+        self
+    }
+
+    /// Set the size jitter (0.0 to 1.0).
+    pub fn with_size_jitter(mut self, jitter: f64) -> Self {
+        self.size_jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the position jitter (0.0 to 0.25 of a cell).
+    ///
+    /// Offsets are further limited per module so the center of the cell
+    /// stays covered by the shape.
+    pub fn with_position_jitter(mut self, jitter: f64) -> Self {
+        self.position_jitter = jitter.clamp(0.0, 0.25);
+        self
+    }
+
+    /// Set the rotation jitter in radians (0 to π).
+    pub fn with_rotation_jitter(mut self, jitter: f64) -> Self {
+        self.rotation_jitter = jitter.clamp(0.0, PI);
+        self
+    }
+
+    /// Set the seed for the random module variation.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Draw the dots as outlines.
+    pub fn with_outline(mut self, outline: OutlineOptions) -> Self {
+        self.outline = Some(outline);
+        self
+    }
+
+    /// Per-module variation described by these options.
+    pub fn jitter(&self) -> DotJitter {
+        DotJitter {
+            scale: self.scale,
+            size_jitter: self.size_jitter,
+            position_jitter: self.position_jitter,
+            rotation_jitter: self.rotation_jitter,
+            seed: self.seed,
+            shape: self.dot_type,
+        }
+    }
 }
@@ -0,0 +1,191 @@
+//! Shadow and glow effects for the drawn layers.
+
+use super::Color;
+
+/// One SVG filter effect. Distances are in pixels; use the color's alpha
+/// for a subtler effect.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Effect {
+    /// Blurred shadow behind the layer, offset by `dx`/`dy`.
+    DropShadow {
+        /// Horizontal offset.
+        dx: f64,
+        /// Vertical offset.
+        dy: f64,
+        /// Blur radius.
+        blur: f64,
+        /// Shadow color.
+        color: Color,
+    },
+    /// Blurred halo behind the layer.
+    Glow {
+        /// Blur radius.
+        blur: f64,
+        /// Glow color.
+        color: Color,
+    },
+    /// Shadow inside the shapes, as if the layer were cut out of the page.
+    InnerShadow {
+        /// Horizontal offset.
+        dx: f64,
+        /// Vertical offset.
+        dy: f64,
+        /// Blur radius.
+        blur: f64,
+        /// Shadow color.
+        color: Color,
+    },
+}
+
+impl Effect {
+    /// Drop shadow offset by `dx`/`dy`.
+    pub fn drop_shadow(dx: f64, dy: f64, blur: f64, color: Color) -> Self {
+        Effect::DropShadow {
+            dx,
+            dy,
+            blur: blur.max(0.0),
+            color,
+        }
+    }
+
+    /// Outer glow.
+    pub fn glow(blur: f64, color: Color) -> Self {
+        Effect::Glow {
+            blur: blur.max(0.0),
+            color,
+        }
+    }
+
+    /// Inner shadow offset by `dx`/`dy`.
+    pub fn inner_shadow(dx: f64, dy: f64, blur: f64, color: Color) -> Self {
+        Effect::InnerShadow {
+            dx,
+            dy,
+            blur: blur.max(0.0),
+            color,
+        }
+    }
+}
+
+/// Effects for each drawn layer.
+///
+/// PDF output rasterizes the effects only, at three times the canvas
+/// resolution; the layers stay vector.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EffectsOptions {
+    /// Effects on the dots, including structural modules and alignment
+    /// patterns.
+    pub dots: Vec<Effect>,
+    /// Effects on the finder patterns.
+    pub corners: Vec<Effect>,
+    /// Effects on the logo.
+    pub image: Vec<Effect>,
+}
+
+impl EffectsOptions {
+    /// Create options with no effects.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an effect to the dots.
+    pub fn with_dots(mut self, effect: Effect) -> Self {
+        self.dots.push(effect);
+        self
+    }
+
+    /// Add an effect to the finder patterns.
+    pub fn with_corners(mut self, effect: Effect) -> Self {
+        self.corners.push(effect);
+        self
+    }
+
+    /// Add an effect to the logo.
+    pub fn with_image(mut self, effect: Effect) -> Self {
+        self.image.push(effect);
+        self
+    }
+
+    /// Returns true if no layer has an effect.
+    pub fn is_empty(&self) -> bool {
+        self.dots.is_empty() && self.corners.is_empty() && self.image.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effects() {
+        use crate::types::OutputFormat;
+        use crate::QRCodeStyling;
+
+        let build = |effects: EffectsOptions| {
+            QRCodeStyling::builder()
+                .data("https://example.com/effects")
+                .effects(effects)
+                .build()
+                .unwrap()
+        };
+        let qr = build(
+            EffectsOptions::new()
+                .with_corners(Effect::drop_shadow(
+                    3.0,
+                    3.0,
+                    4.0,
+                    Color::rgba(0, 0, 0, 120),
+                ))
+                .with_dots(Effect::inner_shadow(
+                    1.0,
+                    1.0,
+                    2.0,
+                    Color::rgba(0, 0, 0, 160),
+                )),
+        );
+        let svg = qr.render_svg().unwrap();
+        assert!(svg.contains("feComponentTransfer"));
+        // Each layer is defined once; shadows go under it, inner shadows
+        // over it.
+        assert_eq!(svg.matches("<g id=\"layer-corners-").count(), 1);
+        assert_eq!(
+            svg.matches("clip-path=\"url(#clip-path-corners-square-color-0-0")
+                .count(),
+            1
+        );
+        let shadow = svg.find("filter=\"url(#effect-corners-0-").unwrap();
+        let corners = svg.rfind("<use href=\"#layer-corners-").unwrap();
+        assert!(svg[corners..].contains("xlink:href=\"#layer-corners-"));
+        assert!(shadow < corners);
+        let dots = svg.find("<use href=\"#layer-dots-").unwrap();
+        let inner = svg.find("filter=\"url(#effect-dots-0-").unwrap();
+        assert!(dots < inner);
+
+        // A glowing logo is embedded once.
+        let logo = QRCodeStyling::builder()
+            .data("https://example.com/effects")
+            .image(vec![0x89, 0x50, 0x4E, 0x47])
+            .effects(EffectsOptions::new().with_image(Effect::glow(4.0, Color::rgb(0, 90, 200))))
+            .build()
+            .unwrap();
+        assert_eq!(logo.render_svg().unwrap().matches("<image ").count(), 1);
+        let report = qr.verify().unwrap();
+        assert!(report.is_ok(), "{:?}", report.error);
+
+        // PDF rasterizes only the effects; a small canvas keeps that quick.
+        let has_image = |effects: EffectsOptions| {
+            let mut qr = build(effects);
+            qr.options_mut().width = 100;
+            qr.options_mut().height = 100;
+            let pdf = qr.render(OutputFormat::Pdf).unwrap();
+            pdf.windows(15).any(|w| w == b"/Subtype /Image")
+        };
+        assert!(has_image(
+            EffectsOptions::new().with_dots(Effect::glow(2.0, Color::BLACK))
+        ));
+        assert!(!has_image(EffectsOptions::new()));
+    }
+}
@@ -1,7 +1,7 @@
 //! Gradient configuration for QR code styling.
 
 use super::Color;
-use crate::types::GradientType;
+use crate::types::{GradientType, GradientUnits, SpreadMethod};
 
 /// A color stop in a gradient.
 #[derive(Debug, Clone, PartialEq)]
@@ -21,46 +21,139 @@ impl ColorStop {
             color,
         }
     }
+
+    /// Set the opacity of the stop color (0.0 to 1.0).
+    pub fn with_opacity(mut self, opacity: f64) -> Self {
+        self.color.a = (opacity.clamp(0.0, 1.0) * 255.0).round() as u8;
+        self
+    }
 }
 
 /// Gradient definition for coloring QR code elements.
+///
+/// Without explicit geometry, linear gradients run across the painted area
+/// at `rotation`, and radial and conic gradients are centered on it.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Gradient {
-    /// Type of gradient (linear or radial).
+    /// Type of gradient (linear, radial or conic).
     pub gradient_type: GradientType,
-    /// Rotation angle in radians (for linear gradients).
+    /// Rotation angle in radians for linear gradients, or the start angle of
+    /// conic gradients, clockwise from the top.
     pub rotation: f64,
     /// Color stops defining the gradient.
     pub color_stops: Vec<ColorStop>,
+    /// How `center`, `radius`, `focal` and `points` are measured.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub units: GradientUnits,
+    /// Center of radial and conic gradients.
+    pub center: Option<(f64, f64)>,
+    /// Radius of radial gradients.
+    pub radius: Option<f64>,
+    /// Focal point of radial gradients; the center if unset.
+    pub focal: Option<(f64, f64)>,
+    /// Start and end points of linear gradients (overrides `rotation`).
+    pub points: Option<((f64, f64), (f64, f64))>,
+    /// How linear and radial gradients continue past their ends.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub spread: SpreadMethod,
 }
 
 impl Gradient {
-    /// Create a new linear gradient.
-    pub fn linear(color_stops: Vec<ColorStop>) -> Self {
+    fn new(gradient_type: GradientType, rotation: f64, color_stops: Vec<ColorStop>) -> Self {
         Self {
-            gradient_type: GradientType::Linear,
-            rotation: 0.0,
+            gradient_type,
+            rotation,
             color_stops,
+            units: GradientUnits::Relative,
+            center: None,
+            radius: None,
+            focal: None,
+            points: None,
+            spread: SpreadMethod::Pad,
         }
     }
 
+    /// Create a new linear gradient.
+    pub fn linear(color_stops: Vec<ColorStop>) -> Self {
+        Self::new(GradientType::Linear, 0.0, color_stops)
+    }
+
     /// Create a new linear gradient with rotation.
     pub fn linear_rotated(rotation: f64, color_stops: Vec<ColorStop>) -> Self {
-        Self {
-            gradient_type: GradientType::Linear,
-            rotation,
-            color_stops,
-        }
+        Self::new(GradientType::Linear, rotation, color_stops)
     }
 
     /// Create a new radial gradient.
     pub fn radial(color_stops: Vec<ColorStop>) -> Self {
-        Self {
-            gradient_type: GradientType::Radial,
-            rotation: 0.0,
-            color_stops,
+        Self::new(GradientType::Radial, 0.0, color_stops)
+    }
+
+    /// Create a new conic gradient starting at `start_angle` radians,
+    /// clockwise from the top.
+    pub fn conic(start_angle: f64, color_stops: Vec<ColorStop>) -> Self {
+        Self::new(GradientType::Conic, start_angle, color_stops)
+    }
+
+    /// Measure the geometry in fractions of the painted area or in pixels.
+    pub fn with_units(mut self, units: GradientUnits) -> Self {
+        self.units = units;
+        self
+    }
+
+    /// Set the center of a radial or conic gradient.
+    pub fn with_center(mut self, x: f64, y: f64) -> Self {
+        self.center = Some((x, y));
+        self
+    }
+
+    /// Set the radius of a radial gradient.
+    pub fn with_radius(mut self, radius: f64) -> Self {
+        self.radius = Some(radius.max(0.0));
+        self
+    }
+
+    /// Set the focal point of a radial gradient.
+    pub fn with_focal(mut self, x: f64, y: f64) -> Self {
+        self.focal = Some((x, y));
+        self
+    }
+
+    /// Run a linear gradient from `start` to `end`.
+    pub fn with_points(mut self, start: (f64, f64), end: (f64, f64)) -> Self {
+        self.points = Some((start, end));
+        self
+    }
+
+    /// Set how the gradient continues past its ends.
+    pub fn with_spread(mut self, spread: SpreadMethod) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Color at `t` (0.0 to 1.0) along the gradient, interpolating color
+    /// and alpha between the stops.
+    pub fn color_at(&self, t: f64) -> Color {
+        let mut stops = self.color_stops.clone();
+        stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+        let (Some(first), Some(last)) = (stops.first(), stops.last()) else {
+            return Color::TRANSPARENT;
+        };
+        if t <= first.offset {
+            return first.color;
         }
+        if t >= last.offset {
+            return last.color;
+        }
+        let i = stops
+            .iter()
+            .position(|s| s.offset > t)
+            .unwrap_or(stops.len() - 1);
+        let (a, b) = (&stops[i - 1], &stops[i]);
+        let f = (t - a.offset) / (b.offset - a.offset);
+        let mut color = a.color.mix(&b.color, f);
+        color.a = (a.color.a as f64 + (b.color.a as f64 - a.color.a as f64) * f).round() as u8;
+        color
     }
 
     /// Create a simple two-color linear gradient.
@@ -85,3 +178,103 @@ impl Default for Gradient {
         Self::simple_linear(Color::BLACK, Color::BLACK)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conic_gradient_and_stop_opacity() {
+        use crate::config::{CornersSquareOptions, DotsOptions};
+        use crate::types::{DotType, OutputFormat};
+        use crate::QRCodeStyling;
+
+        let conic = Gradient::conic(
+            0.5,
+            vec![
+                ColorStop::new(0.0, Color::rgb(200, 0, 80)),
+                ColorStop::new(1.0, Color::rgb(0, 40, 160)).with_opacity(0.8),
+            ],
+        );
+        let linear = Gradient::linear(vec![
+            ColorStop::new(0.0, Color::BLACK),
+            ColorStop::new(1.0, Color::rgb(0, 0, 90)).with_opacity(0.5),
+        ]);
+        let qr = QRCodeStyling::builder()
+            .data("https://example.com/conic")
+            .dots_options(DotsOptions::new(DotType::Rounded).with_gradient(conic))
+            .corners_square_options(CornersSquareOptions::default().with_gradient(linear))
+            .build()
+            .unwrap();
+
+        let svg = qr.render_svg().unwrap();
+        assert!(svg.contains("<pattern id=\"dot-color-"));
+        assert!(svg.contains("<g mask=\"url(#dot-color-"));
+        assert!(svg.contains("stop-color=\"#00005A\" stop-opacity=\"0.5019"));
+        let report = qr.verify().unwrap();
+        assert!(report.is_ok(), "{:?}", report.error);
+
+        let pdf = qr.render(OutputFormat::Pdf).unwrap();
+        // The wedges become a tiling pattern.
+        assert!(pdf.windows(14).any(|w| w == b"/PatternType 1"));
+    }
+
+    #[test]
+    fn test_gradient_geometry() {
+        use crate::config::{CornersSquareOptions, DotsOptions};
+        use crate::QRCodeStyling;
+
+        let stops = || {
+            vec![
+                ColorStop::new(0.0, Color::BLACK),
+                ColorStop::new(1.0, Color::rgb(0, 0, 120)),
+            ]
+        };
+        let build = |dots: Gradient, eyes: Gradient| {
+            QRCodeStyling::builder()
+                .data("https://example.com/geometry")
+                .size(300)
+                .margin(0)
+                .dots_options(DotsOptions::default().with_gradient(dots))
+                .corners_square_options(CornersSquareOptions::default().with_gradient(eyes))
+                .build()
+                .unwrap()
+        };
+
+        // Relative geometry is measured on the canvas for dots.
+        let radial = Gradient::radial(stops())
+            .with_center(0.25, 0.5)
+            .with_radius(0.1)
+            .with_focal(0.2, 0.5)
+            .with_spread(SpreadMethod::Reflect);
+        let linear = Gradient::linear(stops())
+            .with_units(GradientUnits::Absolute)
+            .with_points((10.0, 20.0), (30.0, 40.0));
+        let svg = build(radial, linear).render_svg().unwrap();
+        assert!(svg.contains(
+            "fx=\"60\" fy=\"150\" cx=\"75\" cy=\"150\" r=\"30\" spreadMethod=\"reflect\""
+        ));
+        // Absolute points are the same for every finder pattern.
+        assert_eq!(
+            svg.matches("x1=\"10\" y1=\"20\" x2=\"30\" y2=\"40\"")
+                .count(),
+            3
+        );
+
+        // Relative points turn with rotated finder patterns.
+        let linear = Gradient::linear(stops()).with_points((0.0, 0.5), (1.0, 0.5));
+        let svg = build(Gradient::radial(stops()), linear)
+            .render_svg()
+            .unwrap();
+        let start = svg
+            .find("<linearGradient id=\"corners-square-color-1-0-")
+            .unwrap();
+        let tag = &svg[start..start + svg[start..].find('>').unwrap()];
+        let coord = |attr: &str| -> f64 {
+            let at = tag.find(&format!(" {}=\"", attr)).unwrap() + attr.len() + 3;
+            tag[at..at + tag[at..].find('"').unwrap()].parse().unwrap()
+        };
+        assert!((coord("x1") - coord("x2")).abs() < 1e-9);
+        assert!(coord("y2") > coord("y1"));
+    }
+}
@@ -0,0 +1,42 @@
+//! Halftone picture options.
+
+/// Options for drawing a picture across the whole code area.
+///
+/// Only a small square at the center of each module keeps the exact dark
+/// or light value; the picture shows around it. Finder, timing, alignment
+/// and format modules are drawn in full. Centers use the dot type and
+/// color, but not module colors or jitter.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HalftoneOptions {
+    /// Picture data (PNG, JPEG or WebP), cropped to a centered square.
+    pub image: Vec<u8>,
+    /// Side of the exact center square as a fraction of a module.
+    pub center_size: f64,
+    /// Share of each Reed-Solomon block's correction capacity spent on
+    /// drawing codewords the way the picture wants them (0.0 to 1.0).
+    pub error_budget: f64,
+}
+
+impl HalftoneOptions {
+    /// Create halftone options for a picture.
+    pub fn new(image: Vec<u8>) -> Self {
+        Self {
+            image,
+            center_size: 1.0 / 3.0,
+            error_budget: 0.5,
+        }
+    }
+
+    /// Set the center square size (0.2 to 0.6 of a module).
+    pub fn with_center_size(mut self, size: f64) -> Self {
+        self.center_size = size.clamp(0.2, 0.6);
+        self
+    }
+
+    /// Set the error correction budget (0.0 to 1.0).
+    pub fn with_error_budget(mut self, budget: f64) -> Self {
+        self.error_budget = budget.clamp(0.0, 1.0);
+        self
+    }
+}
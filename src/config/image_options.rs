@@ -5,6 +5,10 @@
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImageOptions {
     /// Size of the image relative to the QR code (0.0 to 1.0).
+    ///
+    /// When the hidden dots would leave a Reed-Solomon block unrecoverable
+    /// the image is drawn smaller; see
+    /// [`CoverageReport::is_shrunk`](crate::analysis::CoverageReport::is_shrunk).
     pub image_size: f64,
     /// Whether to hide dots behind the image.
     pub hide_background_dots: bool,
@@ -14,6 +18,11 @@ pub struct ImageOptions {
     pub cross_origin: Option<String>,
     /// Whether to save the image as a data URL in SVG.
     pub save_as_blob: bool,
+    /// Fail instead of shrinking the image when it would hide more
+    /// codewords than a Reed-Solomon block can correct. Modules under the
+    /// logo count as hidden even when `hide_background_dots` is off.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub strict_coverage: bool,
 }
 
 impl Default for ImageOptions {
@@ -24,6 +33,7 @@ impl Default for ImageOptions {
             margin: 0,
             cross_origin: None,
             save_as_blob: true,
+            strict_coverage: false,
         }
     }
 }
@@ -63,4 +73,10 @@ impl ImageOptions {
         self.save_as_blob = save;
         self
     }
+
+    /// Set whether an unrecoverable image size is an error.
+    pub fn with_strict_coverage(mut self, strict: bool) -> Self {
+        self.strict_coverage = strict;
+        self
+    }
 }
@@ -1,19 +1,36 @@
 //! Configuration types for QR code styling.
 
 mod color;
+mod css_color;
 mod gradient;
+mod pattern_fill;
 mod dot_options;
+mod module_colors;
 mod corner_options;
+mod alignment_options;
+mod structural_options;
 mod background_options;
 mod image_options;
+mod effect_options;
+mod outline_options;
+mod halftone_options;
+mod contrast_options;
 mod qr_options;
 mod options;
 
 pub use color::Color;
 pub use gradient::{Gradient, ColorStop};
+pub use pattern_fill::{PatternFill, PatternKind};
 pub use dot_options::DotsOptions;
-pub use corner_options::{CornersSquareOptions, CornersDotOptions};
-pub use background_options::BackgroundOptions;
+pub use module_colors::{ModuleColorFn, ModuleColorMode, ModuleColors, ModuleInfo};
+pub use corner_options::{CornerArtwork, CornerStyle, CornersDotOptions, CornersOptions, CornersSquareOptions};
+pub use alignment_options::AlignmentOptions;
+pub use structural_options::StructuralOptions;
+pub use background_options::{BackgroundImage, BackgroundOptions};
 pub use image_options::ImageOptions;
+pub use effect_options::{Effect, EffectsOptions};
+pub use outline_options::OutlineOptions;
+pub use halftone_options::HalftoneOptions;
+pub use contrast_options::{ContrastOptions, ContrastPolicy};
 pub use qr_options::QROptions;
 pub use options::{QRCodeStylingOptions, QRCodeStylingBuilder};
@@ -0,0 +1,263 @@
+//! Per-module coloring strategies for dots.
+
+use std::sync::Arc;
+
+use super::Color;
+use crate::core::ModuleKind;
+use crate::utils::SeededRng;
+
+/// What a coloring strategy knows about one cell.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModuleInfo {
+    /// Modules per side of the symbol.
+    pub count: usize,
+    /// Role of the module, or `None` for decorative cells outside the
+    /// symbol (circle shape), which only palettes see.
+    pub kind: Option<ModuleKind>,
+    /// Whether the module is drawn dark (always true for decorative cells).
+    pub dark: bool,
+    /// Distance of the cell center from the symbol center, in half symbol
+    /// sides (0.0 at the center, 1.0 at the middle of an edge).
+    pub distance: f64,
+}
+
+/// Built-in way of picking a palette color for each module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ModuleColorMode {
+    /// Random palette entry per module, reproducible from the seed.
+    Random {
+        /// Seed for the choice.
+        seed: u64,
+    },
+    /// Alternate palette entries like a checkerboard.
+    Checker,
+    /// Horizontal bands of `width` rows per palette entry.
+    RowBands {
+        /// Rows per band.
+        width: usize,
+    },
+    /// Vertical bands of `width` columns per palette entry.
+    ColumnBands {
+        /// Columns per band.
+        width: usize,
+    },
+    /// Blend through the palette from the center out to the corners.
+    Distance,
+}
+
+/// Palette plus the mode that distributes it over the modules.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModuleColors {
+    /// How colors are assigned.
+    pub mode: ModuleColorMode,
+    /// Palette to pick from.
+    pub colors: Vec<Color>,
+}
+
+impl ModuleColors {
+    /// Create a palette with the given mode.
+    pub fn new(mode: ModuleColorMode, colors: Vec<Color>) -> Self {
+        Self { mode, colors }
+    }
+
+    /// Seeded random palette.
+    pub fn random(colors: Vec<Color>, seed: u64) -> Self {
+        Self::new(ModuleColorMode::Random { seed }, colors)
+    }
+
+    /// Checkerboard through the palette.
+    pub fn checker(colors: Vec<Color>) -> Self {
+        Self::new(ModuleColorMode::Checker, colors)
+    }
+
+    /// Horizontal bands `width` rows high.
+    pub fn row_bands(colors: Vec<Color>, width: usize) -> Self {
+        Self::new(ModuleColorMode::RowBands { width }, colors)
+    }
+
+    /// Vertical bands `width` columns wide.
+    pub fn column_bands(colors: Vec<Color>, width: usize) -> Self {
+        Self::new(ModuleColorMode::ColumnBands { width }, colors)
+    }
+
+    /// Blend from the first color at the center to the last at the corners.
+    pub fn distance(colors: Vec<Color>) -> Self {
+        Self::new(ModuleColorMode::Distance, colors)
+    }
+
+    /// Color of the module at `row`/`col`, or `None` for an empty palette.
+    pub fn color(&self, row: usize, col: usize, info: &ModuleInfo) -> Option<Color> {
+        let n = self.colors.len();
+        if n == 0 {
+            return None;
+        }
+        let index = match self.mode {
+            ModuleColorMode::Random { seed } => {
+                SeededRng::for_cell(seed, row, col).next_u64() as usize % n
+            }
+            ModuleColorMode::Checker => (row + col) % n,
+            ModuleColorMode::RowBands { width } => row / width.max(1) % n,
+            ModuleColorMode::ColumnBands { width } => col / width.max(1) % n,
+            ModuleColorMode::Distance => {
+                let t = (info.distance / std::f64::consts::SQRT_2).min(1.0) * (n - 1) as f64;
+                let i = (t.floor() as usize).min(n - 1);
+                let next = (i + 1).min(n - 1);
+                return Some(self.colors[i].mix(&self.colors[next], t - i as f64));
+            }
+        };
+        Some(self.colors[index])
+    }
+}
+
+/// Shared user-supplied `Fn(row, col, ModuleInfo) -> Color`, stored in
+/// [`DotsOptions`](super::DotsOptions).
+///
+/// Two handles are equal when they point to the same function.
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct ModuleColorFn(Arc<dyn Fn(usize, usize, ModuleInfo) -> Color + Send + Sync>);
+
+impl ModuleColorFn {
+    /// Wrap a coloring function.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(usize, usize, ModuleInfo) -> Color + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    /// Color of the module at `row`/`col`.
+    pub fn color(&self, row: usize, col: usize, info: ModuleInfo) -> Color {
+        (self.0)(row, col, info)
+    }
+}
+
+impl std::fmt::Debug for ModuleColorFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ModuleColorFn")
+    }
+}
+
+impl PartialEq for ModuleColorFn {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(distance: f64) -> ModuleInfo {
+        ModuleInfo {
+            count: 21,
+            kind: Some(ModuleKind::Data),
+            dark: true,
+            distance,
+        }
+    }
+
+    fn palette() -> Vec<Color> {
+        vec![Color::BLACK, Color::rgb(200, 0, 0), Color::rgb(0, 0, 200)]
+    }
+
+    #[test]
+    fn test_patterns() {
+        let checker = ModuleColors::checker(palette());
+        assert_eq!(checker.color(0, 0, &info(0.0)), Some(Color::BLACK));
+        assert_eq!(checker.color(0, 1, &info(0.0)), Some(Color::rgb(200, 0, 0)));
+
+        let rows = ModuleColors::row_bands(palette(), 2);
+        assert_eq!(rows.color(1, 9, &info(0.0)), Some(Color::BLACK));
+        assert_eq!(rows.color(2, 0, &info(0.0)), Some(Color::rgb(200, 0, 0)));
+        assert_eq!(rows.color(6, 0, &info(0.0)), Some(Color::BLACK));
+
+        let columns = ModuleColors::column_bands(palette(), 3);
+        assert_eq!(columns.color(0, 8, &info(0.0)), Some(Color::rgb(0, 0, 200)));
+        assert_eq!(ModuleColors::checker(vec![]).color(0, 0, &info(0.0)), None);
+    }
+
+    #[test]
+    fn test_random_is_seeded() {
+        let a = ModuleColors::random(palette(), 1);
+        let b = ModuleColors::random(palette(), 2);
+        let pick = |p: &ModuleColors| -> Vec<Color> {
+            (0..20)
+                .map(|i| p.color(i, 2 * i, &info(0.0)).unwrap())
+                .collect()
+        };
+        assert_eq!(pick(&a), pick(&a));
+        assert_ne!(pick(&a), pick(&b));
+        assert!(pick(&a).iter().all(|c| palette().contains(c)));
+    }
+
+    #[test]
+    fn test_distance_blends() {
+        let colors = ModuleColors::distance(vec![Color::BLACK, Color::rgb(0, 0, 200)]);
+        assert_eq!(colors.color(10, 10, &info(0.0)), Some(Color::BLACK));
+        assert_eq!(colors.color(0, 0, &info(2.0)), Some(Color::rgb(0, 0, 200)));
+        let middle = colors
+            .color(0, 10, &info(std::f64::consts::SQRT_2 / 2.0))
+            .unwrap();
+        assert!((90..=110).contains(&middle.b));
+    }
+
+    #[test]
+    fn test_module_colors_render() {
+        use crate::config::DotsOptions;
+        use crate::types::{DotType, OutputFormat, ShapeType};
+        use crate::QRCodeStyling;
+
+        let palette = vec![
+            Color::rgb(20, 40, 120),
+            Color::rgb(140, 20, 60),
+            Color::rgb(10, 90, 40),
+        ];
+        let build = |dots_options: DotsOptions| {
+            QRCodeStyling::builder()
+                .data("https://example.com/palette")
+                .shape(ShapeType::Circle)
+                .dots_options(dots_options)
+                .build()
+                .unwrap()
+        };
+        let strategies = [
+            DotsOptions::new(DotType::Rounded)
+                .with_module_colors(ModuleColors::random(palette.clone(), 9)),
+            DotsOptions::new(DotType::Liquid)
+                .with_module_colors(ModuleColors::checker(palette.clone())),
+            DotsOptions::new(DotType::Dots)
+                .with_module_colors(ModuleColors::row_bands(palette.clone(), 3)),
+        ];
+        for dots_options in strategies {
+            let svg = build(dots_options).render_svg().unwrap();
+            for color in &palette {
+                assert!(
+                    svg.contains(&format!("<path fill=\"{}\"", color.to_hex())),
+                    "{:?}",
+                    color
+                );
+            }
+        }
+
+        // The palette only changes fills, so one round trip covers the
+        // geometry; the callback also colors function modules.
+        let qr = build(
+            DotsOptions::new(DotType::Square).with_module_color_fn(|row, col, info| {
+                assert!(info.kind.is_some() && row < info.count && col < info.count);
+                match info.kind {
+                    Some(kind) if kind.is_function() => Color::rgb(120, 0, 0),
+                    _ if col % 2 == 0 => Color::rgb(0, 0, 90),
+                    _ => Color::BLACK,
+                }
+            }),
+        );
+        assert!(qr.render_svg().unwrap().contains("<path fill=\"#780000\""));
+        let report = qr.verify().unwrap();
+        assert!(report.is_ok(), "{:?}", report.error);
+        assert!(!qr.render(OutputFormat::Pdf).unwrap().is_empty());
+    }
+}
@@ -1,11 +1,13 @@
 //! Main QR code styling options with builder pattern.
 
 use super::{
-    BackgroundOptions, CornersDotOptions, CornersSquareOptions, DotsOptions, ImageOptions,
-    QROptions,
+    AlignmentOptions, BackgroundOptions, ContrastOptions, CornersDotOptions, CornersOptions,
+    CornersSquareOptions, DotsOptions, EffectsOptions, HalftoneOptions, ImageOptions, QROptions,
+    StructuralOptions,
 };
 use crate::error::{QRError, Result};
-use crate::types::ShapeType;
+use crate::presets::{self, Theme};
+use crate::types::{CornerDotType, CornerSquareType, DotType, ShapeType};
 
 /// Main configuration for QR code styling.
 #[derive(Debug, Clone, PartialEq)]
@@ -27,14 +29,30 @@ pub struct QRCodeStylingOptions {
     pub qr_options: QROptions,
     /// Dot styling options.
     pub dots_options: DotsOptions,
+    /// Timing, alignment and format module styling.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub structural_options: StructuralOptions,
+    /// Whole alignment pattern styling, instead of per-module dots.
+    pub alignment_options: Option<AlignmentOptions>,
     /// Corner square styling options.
     pub corners_square_options: CornersSquareOptions,
     /// Corner dot styling options.
     pub corners_dot_options: CornersDotOptions,
+    /// Finder pattern orientation and per-corner overrides.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub corners_options: CornersOptions,
     /// Background styling options.
     pub background_options: BackgroundOptions,
     /// Image embedding options.
     pub image_options: ImageOptions,
+    /// Shadow and glow effects per layer.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub effects: EffectsOptions,
+    /// Foreground/background contrast checking.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub contrast_options: ContrastOptions,
+    /// Optional picture drawn across the whole code in halftone.
+    pub halftone: Option<HalftoneOptions>,
 }
 
 impl Default for QRCodeStylingOptions {
@@ -48,10 +66,16 @@ impl Default for QRCodeStylingOptions {
             image: None,
             qr_options: QROptions::default(),
             dots_options: DotsOptions::default(),
+            structural_options: StructuralOptions::default(),
+            alignment_options: None,
             corners_square_options: CornersSquareOptions::default(),
             corners_dot_options: CornersDotOptions::default(),
+            corners_options: CornersOptions::default(),
             background_options: BackgroundOptions::default(),
             image_options: ImageOptions::default(),
+            effects: EffectsOptions::default(),
+            contrast_options: ContrastOptions::default(),
+            halftone: None,
         }
     }
 }
@@ -67,10 +91,49 @@ pub struct QRCodeStylingBuilder {
     image: Option<Vec<u8>>,
     qr_options: Option<QROptions>,
     dots_options: Option<DotsOptions>,
+    structural_options: Option<StructuralOptions>,
+    alignment_options: Option<AlignmentOptions>,
     corners_square_options: Option<CornersSquareOptions>,
     corners_dot_options: Option<CornersDotOptions>,
+    corners_options: Option<CornersOptions>,
     background_options: Option<BackgroundOptions>,
     image_options: Option<ImageOptions>,
+    effects: Option<EffectsOptions>,
+    contrast_options: Option<ContrastOptions>,
+    halftone: Option<HalftoneOptions>,
+    preset: Option<String>,
+    theme: Option<Theme>,
+}
+
+impl QRCodeStylingOptions {
+    /// Reject settings that cannot be drawn as configured.
+    pub(crate) fn validate(&self) -> Result<()> {
+        let structural = &self.structural_options;
+        let mut dots = std::iter::once(&self.dots_options).chain(
+            [
+                &structural.timing,
+                &structural.alignment,
+                &structural.format_info,
+            ]
+            .into_iter()
+            .flatten(),
+        );
+        if dots.any(|d| d.dot_type == DotType::Custom && d.custom_drawer.is_none()) {
+            return Err(QRError::InvalidOptions(
+                "DotType::Custom needs a custom drawer".to_string(),
+            ));
+        }
+        if let Some(alignment) = &self.alignment_options {
+            if alignment.ring_type == CornerSquareType::Custom
+                || alignment.center_type == CornerDotType::Custom
+            {
+                return Err(QRError::InvalidOptions(
+                    "alignment patterns have no custom artwork".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
 }
 
 impl QRCodeStylingBuilder {
@@ -134,6 +197,18 @@ impl QRCodeStylingBuilder {
         self
     }
 
+    /// Set timing, alignment and format module styling.
+    pub fn structural_options(mut self, options: StructuralOptions) -> Self {
+        self.structural_options = Some(options);
+        self
+    }
+
+    /// Draw alignment patterns as whole 5×5 units.
+    pub fn alignment_options(mut self, options: AlignmentOptions) -> Self {
+        self.alignment_options = Some(options);
+        self
+    }
+
     /// Set corner square styling options.
     pub fn corners_square_options(mut self, options: CornersSquareOptions) -> Self {
         self.corners_square_options = Some(options);
@@ -146,6 +221,12 @@ impl QRCodeStylingBuilder {
         self
     }
 
+    /// Set finder pattern orientation and per-corner overrides.
+    pub fn corners_options(mut self, options: CornersOptions) -> Self {
+        self.corners_options = Some(options);
+        self
+    }
+
     /// Set background styling options.
     pub fn background_options(mut self, options: BackgroundOptions) -> Self {
         self.background_options = Some(options);
@@ -158,6 +239,41 @@ impl QRCodeStylingBuilder {
         self
     }
 
+    /// Set shadow and glow effects.
+    pub fn effects(mut self, effects: EffectsOptions) -> Self {
+        self.effects = Some(effects);
+        self
+    }
+
+    /// Set contrast checking options.
+    pub fn contrast_options(mut self, options: ContrastOptions) -> Self {
+        self.contrast_options = Some(options);
+        self
+    }
+
+    /// Draw a picture across the whole code in halftone.
+    pub fn halftone(mut self, options: HalftoneOptions) -> Self {
+        self.halftone = Some(options);
+        self
+    }
+
+    /// Style with the registered preset `name` (see [`presets`]).
+    ///
+    /// Dots, corner and background options set on the builder override the
+    /// preset's, whatever the order of the calls. Unknown names fail the build.
+    pub fn preset(mut self, name: impl Into<String>) -> Self {
+        self.preset = Some(name.into());
+        self.theme = None;
+        self
+    }
+
+    /// Style with a theme, like [`preset`](Self::preset) without the lookup.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self.preset = None;
+        self
+    }
+
     /// Build the QRCodeStylingOptions (internal use).
     pub(crate) fn build_options(self) -> Result<QRCodeStylingOptions> {
         let data = self.data.ok_or(QRError::MissingData)?;
@@ -166,6 +282,12 @@ impl QRCodeStylingBuilder {
             return Err(QRError::MissingData);
         }
 
+        let theme = match (self.theme, self.preset) {
+            (Some(theme), _) => theme,
+            (None, Some(name)) => presets::get(&name).ok_or(QRError::UnknownPreset(name))?,
+            (None, None) => Theme::default(),
+        };
+
         let width = self.width.unwrap_or(300);
         let height = self.height.unwrap_or(300);
 
@@ -181,11 +303,21 @@ impl QRCodeStylingBuilder {
             shape: self.shape.unwrap_or(ShapeType::Square),
             image: self.image,
             qr_options: self.qr_options.unwrap_or_default(),
-            dots_options: self.dots_options.unwrap_or_default(),
-            corners_square_options: self.corners_square_options.unwrap_or_default(),
-            corners_dot_options: self.corners_dot_options.unwrap_or_default(),
-            background_options: self.background_options.unwrap_or_default(),
+            dots_options: self.dots_options.unwrap_or(theme.dots_options),
+            structural_options: self.structural_options.unwrap_or_default(),
+            alignment_options: self.alignment_options,
+            corners_square_options: self
+                .corners_square_options
+                .unwrap_or(theme.corners_square_options),
+            corners_dot_options: self
+                .corners_dot_options
+                .unwrap_or(theme.corners_dot_options),
+            corners_options: self.corners_options.unwrap_or_default(),
+            background_options: self.background_options.unwrap_or(theme.background_options),
             image_options: self.image_options.unwrap_or_default(),
+            effects: self.effects.unwrap_or_default(),
+            contrast_options: self.contrast_options.unwrap_or_default(),
+            halftone: self.halftone,
         })
     }
 }
@@ -214,9 +346,90 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_builder_preset_overrides() {
+        use crate::types::DotType;
+
+        let options = QRCodeStylingBuilder::new()
+            .dots_options(DotsOptions::new(DotType::Square))
+            .preset("rounded-navy")
+            .data("https://example.com")
+            .build_options()
+            .unwrap();
+        let theme = presets::get("rounded-navy").unwrap();
+        assert_eq!(options.dots_options.dot_type, DotType::Square);
+        assert_eq!(options.corners_square_options, theme.corners_square_options);
+
+        let result = QRCodeStylingBuilder::new()
+            .data("x")
+            .preset("no-such-preset")
+            .build_options();
+        assert!(matches!(result, Err(QRError::UnknownPreset(name)) if name == "no-such-preset"));
+    }
+
     #[test]
     fn test_builder_empty_data() {
         let result = QRCodeStylingBuilder::new().data("").build_options();
         assert!(result.is_err());
     }
+
+    /// Options saved by version 0.1.1, before any of the later fields.
+    #[cfg(feature = "serde")]
+    const CONFIG_0_1_1: &str = r#"{
+        "data": "https://example.com/saved", "width": 320, "height": 320, "margin": 8,
+        "shape": "circle", "image": null,
+        "qr_options": {"type_number": 0, "error_correction_level": "H", "mode": null},
+        "dots_options": {
+            "dot_type": "classy", "color": {"r": 0, "g": 0, "b": 0, "a": 255},
+            "gradient": {"gradient_type": "linear", "rotation": 0.0, "color_stops": [
+                {"offset": 0.0, "color": {"r": 10, "g": 20, "b": 90, "a": 255}},
+                {"offset": 1.0, "color": {"r": 80, "g": 0, "b": 60, "a": 255}}
+            ]},
+            "round_size": true
+        },
+        "corners_square_options": {
+            "square_type": "extra-rounded", "color": {"r": 0, "g": 60, "b": 0, "a": 255},
+            "gradient": null
+        },
+        "corners_dot_options": {
+            "dot_type": "dot", "color": {"r": 0, "g": 0, "b": 0, "a": 255},
+            "gradient": {"gradient_type": "radial", "rotation": 0.0, "color_stops": [
+                {"offset": 0.0, "color": {"r": 0, "g": 0, "b": 0, "a": 255}},
+                {"offset": 1.0, "color": {"r": 40, "g": 40, "b": 40, "a": 255}}
+            ]}
+        },
+        "background_options": {
+            "color": {"r": 255, "g": 255, "b": 255, "a": 255}, "gradient": null, "round": 0.2
+        },
+        "image_options": {
+            "image_size": 0.3, "hide_background_dots": true, "margin": 0,
+            "cross_origin": null, "save_as_blob": true
+        }
+    }"#;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_load_0_1_1_config() {
+        let options: QRCodeStylingOptions = serde_json::from_str(CONFIG_0_1_1).unwrap();
+        assert_eq!(options.width, 320);
+        assert_eq!(options.shape, ShapeType::Circle);
+        // Fields added since then take their defaults.
+        let defaults = QRCodeStylingOptions::default();
+        assert_eq!(options.dots_options.scale, 1.0);
+        assert_eq!(
+            options.corners_square_options.radius,
+            defaults.corners_square_options.radius
+        );
+        assert_eq!(options.structural_options, defaults.structural_options);
+        assert_eq!(options.corners_options, defaults.corners_options);
+        assert_eq!(options.effects, defaults.effects);
+        assert_eq!(options.contrast_options, defaults.contrast_options);
+        assert!(!options.image_options.strict_coverage);
+
+        let json = serde_json::to_string(&options).unwrap();
+        assert_eq!(
+            serde_json::from_str::<QRCodeStylingOptions>(&json).unwrap(),
+            options
+        );
+    }
 }
@@ -0,0 +1,108 @@
+//! Stroke-only drawing for dots and finder patterns.
+
+use super::Color;
+
+/// Draws shapes as outlines along their edges instead of filling them.
+///
+/// Widths are fractions of a module, so an outline keeps its weight at
+/// every size. Hollow modules read as light, so codes drawn as outlines
+/// only scan reliably with a dark `fill` or a width close to half a module.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutlineOptions {
+    /// Line width as a fraction of a module (0.05 to 0.5).
+    pub width: f64,
+    /// Gap before a second, inner line, as a fraction of a module.
+    pub double_gap: Option<f64>,
+    /// Color painted inside the outline.
+    pub fill: Option<Color>,
+}
+
+impl Default for OutlineOptions {
+    fn default() -> Self {
+        Self {
+            width: 0.25,
+            double_gap: None,
+            fill: None,
+        }
+    }
+}
+
+impl OutlineOptions {
+    /// Single outline of the given width.
+    pub fn new(width: f64) -> Self {
+        Self::default().with_width(width)
+    }
+
+    /// Set the line width (clamped to 0.05..=0.5 of a module).
+    pub fn with_width(mut self, width: f64) -> Self {
+        self.width = width.clamp(0.05, 0.5);
+        self
+    }
+
+    /// Draw a second line inside the first, `gap` of a module apart.
+    pub fn with_double(mut self, gap: f64) -> Self {
+        self.double_gap = Some(gap.clamp(0.0, 0.5));
+        self
+    }
+
+    /// Fill the inside of the outline.
+    pub fn with_fill(mut self, color: Color) -> Self {
+        self.fill = Some(color);
+        self
+    }
+
+    /// Bands covered by lines, as (start, end) distances in from the shape
+    /// edge, in modules.
+    pub fn bands(&self) -> Vec<(f64, f64)> {
+        let mut bands = vec![(0.0, self.width)];
+        if let Some(gap) = self.double_gap {
+            let start = self.width + gap;
+            bands.push((start, start + self.width));
+        }
+        bands
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outline_modes() {
+        use crate::config::{CornersDotOptions, CornersSquareOptions, DotsOptions};
+        use crate::types::{CornerSquareType, DotType};
+        use crate::QRCodeStyling;
+
+        let build = |outline: OutlineOptions| {
+            QRCodeStyling::builder()
+                .data("https://example.com/outline")
+                .dots_options(DotsOptions::new(DotType::Square).with_outline(outline.clone()))
+                .corners_square_options(
+                    CornersSquareOptions::new(CornerSquareType::Rounded)
+                        .with_radius(0.3)
+                        .with_outline(outline.clone()),
+                )
+                .corners_dot_options(CornersDotOptions::default().with_outline(outline))
+                .build()
+                .unwrap()
+        };
+
+        let svg = build(OutlineOptions::new(0.2).with_double(0.1))
+            .render_svg()
+            .unwrap();
+        assert_eq!(svg.matches("<mask id=\"outline-").count(), 7);
+        assert!(svg.contains("<g stroke=\"black\""));
+        // Touching square modules are outlined as one region.
+        let start = svg.find("<clipPath id=\"clip-path-dot-color-").unwrap();
+        let end = start + svg[start..].find("</clipPath>").unwrap();
+        assert_eq!(svg[start..end].matches("<path").count(), 1);
+
+        let qr = build(OutlineOptions::new(0.2).with_fill(Color::from_hex("#333333").unwrap()));
+        let svg = qr.render_svg().unwrap();
+        assert!(svg.contains("fill=\"#333333\""));
+        assert!(!svg.contains("<g stroke=\"black\""));
+        let report = qr.verify().unwrap();
+        assert!(report.is_ok(), "{:?}", report.error);
+    }
+}
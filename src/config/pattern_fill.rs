@@ -0,0 +1,148 @@
+//! Pattern and texture fills.
+
+use super::Color;
+
+/// What a pattern fill repeats.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum PatternKind {
+    /// Stripes half a tile wide; turn them with the rotation.
+    Stripes,
+    /// Two diagonal lines crossing in every tile.
+    Crosshatch,
+    /// Staggered polka dots.
+    Polka,
+    /// Seeded random specks mixing the two colors.
+    Noise,
+    /// Raster (PNG, JPEG, WebP) or SVG image, such as a wood or marble photo.
+    Image(Vec<u8>),
+}
+
+/// Fills shapes with a repeating pattern or an image texture instead of a
+/// color or gradient.
+///
+/// Scanners see the average of the two colors, so keep both dark enough on
+/// light backgrounds. Contrast checks only see the options' color and
+/// gradient; use [`verify`](crate::QRCodeStyling::verify) for patterned
+/// codes.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PatternFill {
+    /// What to repeat.
+    pub kind: PatternKind,
+    /// Color of the pattern's marks (not used by images).
+    pub color: Color,
+    /// Color between the marks (not used by images).
+    pub background: Color,
+    /// Tile size in modules; images are stretched over the painted area
+    /// if unset, procedural patterns use one module.
+    pub tile: Option<f64>,
+    /// Rotation of the pattern in radians.
+    pub rotation: f64,
+    /// Seed for [`PatternKind::Noise`].
+    pub seed: u64,
+}
+
+impl PatternFill {
+    /// Procedural pattern of `color` marks over `background`.
+    pub fn new(kind: PatternKind, color: Color, background: Color) -> Self {
+        Self {
+            kind,
+            color,
+            background,
+            tile: None,
+            rotation: 0.0,
+            seed: 0,
+        }
+    }
+
+    /// Image texture, stretched over the painted area.
+    pub fn texture(image: Vec<u8>) -> Self {
+        Self::new(PatternKind::Image(image), Color::BLACK, Color::BLACK)
+    }
+
+    /// Set the tile size in modules.
+    pub fn with_tile(mut self, modules: f64) -> Self {
+        self.tile = Some(modules.max(0.1));
+        self
+    }
+
+    /// Set the rotation in radians.
+    pub fn with_rotation(mut self, rotation: f64) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Set the noise seed.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern_fills() {
+        use crate::config::{CornersDotOptions, CornersSquareOptions, DotsOptions};
+        use crate::types::{DotType, OutputFormat};
+        use crate::QRCodeStyling;
+
+        let ink = Color::rgb(20, 20, 60);
+        let navy = Color::rgb(40, 40, 110);
+        let build = |kind: PatternKind| {
+            let pattern = PatternFill::new(kind, ink, navy)
+                .with_tile(0.5)
+                .with_rotation(0.3);
+            QRCodeStyling::builder()
+                .data("https://example.com/pattern")
+                .dots_options(DotsOptions::new(DotType::Rounded).with_pattern(pattern.clone()))
+                .corners_square_options(
+                    CornersSquareOptions::default().with_pattern(pattern.clone()),
+                )
+                .corners_dot_options(CornersDotOptions::default().with_pattern(pattern))
+                .build()
+                .unwrap()
+        };
+
+        for kind in [
+            PatternKind::Stripes,
+            PatternKind::Crosshatch,
+            PatternKind::Polka,
+            PatternKind::Noise,
+        ] {
+            let svg = build(kind.clone()).render_svg().unwrap();
+            assert!(svg.contains("<pattern id=\"dot-color-"), "{:?}", kind);
+            assert_eq!(
+                svg.matches("<pattern id=\"corners-").count(),
+                6,
+                "{:?}",
+                kind
+            );
+            assert!(svg.contains("patternTransform=\"rotate("));
+        }
+        // Both pattern colors are dark, so one round trip covers them all.
+        let report = build(PatternKind::Noise).verify().unwrap();
+        assert!(report.is_ok(), "{:?}", report.error);
+
+        let pdf = build(PatternKind::Stripes)
+            .render(OutputFormat::Pdf)
+            .unwrap();
+        assert!(pdf.windows(14).any(|w| w == b"/PatternType 1"));
+
+        let texture = br##"<svg xmlns="http://www.w3.org/2000/svg" width="4" height="4"><rect width="4" height="4" fill="#301010"/></svg>"##;
+        let svg = QRCodeStyling::builder()
+            .data("https://example.com/texture")
+            .dots_options(
+                DotsOptions::default().with_pattern(PatternFill::texture(texture.to_vec())),
+            )
+            .build()
+            .unwrap()
+            .render_svg()
+            .unwrap();
+        assert!(svg.contains("preserveAspectRatio=\"xMidYMid slice\""));
+    }
+}
@@ -12,6 +12,8 @@ pub struct QROptions {
     pub error_correction_level: ErrorCorrectionLevel,
     /// Encoding mode (None = auto-detect).
     pub mode: Option<Mode>,
+    /// Mask pattern 0-7 (None = pick the best).
+    pub mask_pattern: Option<u8>,
 }
 
 impl Default for QROptions {
@@ -20,6 +22,7 @@ impl Default for QROptions {
             type_number: 0, // Auto
             error_correction_level: ErrorCorrectionLevel::Q,
             mode: None, // Auto-detect
+            mask_pattern: None,
         }
     }
 }
@@ -47,4 +50,10 @@ impl QROptions {
         self.mode = Some(mode);
         self
     }
+
+    /// Force a mask pattern (0-7); others fail when the code is built.
+    pub fn with_mask_pattern(mut self, mask: u8) -> Self {
+        self.mask_pattern = Some(mask);
+        self
+    }
 }
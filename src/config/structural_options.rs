@@ -0,0 +1,102 @@
+//! Separate styling for structural (function pattern) modules.
+
+use super::DotsOptions;
+use crate::core::ModuleKind;
+
+/// Dot styles for structural modules, each falling back to
+/// [`DotsOptions`] when unset.
+///
+/// Finder patterns are styled by the corner options instead.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StructuralOptions {
+    /// Timing pattern modules (row and column 6).
+    pub timing: Option<DotsOptions>,
+    /// Alignment pattern modules, unless `AlignmentOptions` draws them
+    /// whole.
+    pub alignment: Option<DotsOptions>,
+    /// Format and version information modules, and the dark module.
+    pub format_info: Option<DotsOptions>,
+}
+
+impl StructuralOptions {
+    /// Create options with no overrides.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Style timing pattern modules.
+    pub fn with_timing(mut self, options: DotsOptions) -> Self {
+        self.timing = Some(options);
+        self
+    }
+
+    /// Style alignment pattern modules.
+    pub fn with_alignment(mut self, options: DotsOptions) -> Self {
+        self.alignment = Some(options);
+        self
+    }
+
+    /// Style format and version information modules.
+    pub fn with_format_info(mut self, options: DotsOptions) -> Self {
+        self.format_info = Some(options);
+        self
+    }
+
+    /// Style timing, alignment and format modules alike.
+    pub fn with_all(self, options: DotsOptions) -> Self {
+        self.with_timing(options.clone())
+            .with_alignment(options.clone())
+            .with_format_info(options)
+    }
+
+    /// Override for modules of `kind`, if any.
+    pub fn style_for(&self, kind: ModuleKind) -> Option<&DotsOptions> {
+        match kind {
+            ModuleKind::Timing => self.timing.as_ref(),
+            ModuleKind::Alignment => self.alignment.as_ref(),
+            ModuleKind::FormatInfo | ModuleKind::VersionInfo | ModuleKind::DarkModule => {
+                self.format_info.as_ref()
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_for() {
+        let options = StructuralOptions::new().with_timing(DotsOptions::default());
+        assert!(options.style_for(ModuleKind::Timing).is_some());
+        assert!(options.style_for(ModuleKind::DarkModule).is_none());
+        assert!(options.style_for(ModuleKind::Data).is_none());
+    }
+
+    #[test]
+    fn test_structural_styles_decode() {
+        use crate::config::Color;
+        use crate::types::DotType;
+        use crate::QRCodeStyling;
+
+        let crisp = DotsOptions::new(DotType::Square).with_color(Color::rgb(120, 0, 0));
+        let qr = QRCodeStyling::builder()
+            .data("https://example.com/structural/modules/with/alignment")
+            .dots_options(DotsOptions::new(DotType::Dots))
+            .structural_options(
+                StructuralOptions::new()
+                    .with_alignment(crisp.clone())
+                    .with_format_info(crisp.with_type(DotType::Rounded)),
+            )
+            .build()
+            .unwrap();
+        let svg = qr.render_svg().unwrap();
+        assert!(svg.contains("clip-path-alignment-color-"));
+        assert!(svg.contains("clip-path-format-color-"));
+        assert!(!svg.contains("clip-path-timing-color-"));
+        let report = qr.verify().unwrap();
+        assert!(report.is_ok(), "{:?}", report.error);
+    }
+}
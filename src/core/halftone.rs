@@ -0,0 +1,247 @@
+//! Module values for halftone picture codes.
+
+use std::io::Cursor;
+
+use image::imageops::FilterType;
+use image::{DynamicImage, GenericImageView, ImageFormat};
+
+use super::{ModuleKind, QRMatrix};
+use crate::analysis::CoverageReport;
+use crate::config::HalftoneOptions;
+use crate::error::{QRError, Result};
+
+/// Largest side of the picture embedded in the output, in pixels.
+const MAX_PICTURE_SIDE: u32 = 1024;
+
+/// Picture and module values for one halftone rendering.
+#[derive(Debug, Clone)]
+pub(crate) struct Halftone {
+    /// The picture cropped to a centered square, PNG encoded.
+    pub picture: Vec<u8>,
+    /// Value drawn at each module center, row-major.
+    pub dark: Vec<bool>,
+    count: usize,
+}
+
+impl Halftone {
+    /// Plan a halftone rendering of `matrix`.
+    ///
+    /// Codewords are drawn as the picture wants them, most mismatched
+    /// first, while each Reed-Solomon block stays within
+    /// `HalftoneOptions::error_budget` of its correction capacity, after
+    /// whatever the logo already hides.
+    pub(crate) fn new(
+        options: &HalftoneOptions,
+        matrix: &QRMatrix,
+        coverage: &CoverageReport,
+    ) -> Result<Self> {
+        let picture = square_picture(&options.image)?;
+        let count = matrix.size();
+        let luminance: Vec<f64> = picture
+            .resize_exact(count as u32, count as u32, FilterType::Triangle)
+            .to_luma8()
+            .pixels()
+            .map(|p| p[0] as f64 / 255.0)
+            .collect();
+        let wants_dark = |row: usize, col: usize| luminance[row * count + col] < 0.5;
+
+        let layout = matrix.layout();
+        let mut dark: Vec<bool> = (0..count * count)
+            .map(|i| matrix.is_dark(i / count, i % count))
+            .collect();
+
+        // Per codeword: how much closer to the picture drawing it as the
+        // picture wants would be.
+        let mut gains: Vec<Vec<f64>> = layout
+            .blocks()
+            .iter()
+            .map(|b| vec![0.0; b.data_codewords + b.ec_codewords])
+            .collect();
+        for row in 0..count {
+            for col in 0..count {
+                let lum = luminance[row * count + col];
+                let mismatch = |dark: bool| if dark { lum } else { 1.0 - lum };
+                match layout.codeword(row, col) {
+                    Some(cw) => {
+                        let gain =
+                            mismatch(dark[row * count + col]) - mismatch(wants_dark(row, col));
+                        gains[cw.block][cw.index] += gain;
+                    }
+                    // Remainder bits carry nothing, so they always follow the picture.
+                    None if layout.kind(row, col) == ModuleKind::Remainder => {
+                        dark[row * count + col] = wants_dark(row, col);
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        let mut flipped: Vec<Vec<bool>> = gains.iter().map(|g| vec![false; g.len()]).collect();
+        for (block, block_gains) in gains.iter().enumerate() {
+            let damage = coverage.blocks.get(block).map_or(0, |b| b.damaged());
+            let capacity = layout.blocks()[block].correctable as f64 * options.error_budget;
+            let budget = (capacity.floor() as usize).saturating_sub(damage);
+
+            let mut order: Vec<usize> = (0..block_gains.len())
+                .filter(|&i| block_gains[i] > 0.0)
+                .collect();
+            order.sort_by(|&a, &b| block_gains[b].total_cmp(&block_gains[a]));
+            for &index in order.iter().take(budget) {
+                flipped[block][index] = true;
+            }
+        }
+
+        for row in 0..count {
+            for col in 0..count {
+                if let Some(cw) = layout.codeword(row, col) {
+                    if flipped[cw.block][cw.index] {
+                        dark[row * count + col] = wants_dark(row, col);
+                    }
+                }
+            }
+        }
+
+        let mut png = Vec::new();
+        picture.write_to(&mut Cursor::new(&mut png), ImageFormat::Png)?;
+        Ok(Self {
+            picture: png,
+            dark,
+            count,
+        })
+    }
+
+    /// Value drawn at the center of the module at `row`/`col`.
+    pub(crate) fn is_dark(&self, row: usize, col: usize) -> bool {
+        self.dark[row * self.count + col]
+    }
+}
+
+/// Decode `bytes` and crop the largest centered square, capped in size.
+fn square_picture(bytes: &[u8]) -> Result<DynamicImage> {
+    let picture = image::load_from_memory(bytes)?;
+    let (width, height) = picture.dimensions();
+    let side = width.min(height);
+    if side == 0 {
+        return Err(QRError::ImageLoadError(
+            "empty halftone picture".to_string(),
+        ));
+    }
+    let square = picture.crop_imm((width - side) / 2, (height - side) / 2, side, side);
+    Ok(if side > MAX_PICTURE_SIDE {
+        square.resize_exact(MAX_PICTURE_SIDE, MAX_PICTURE_SIDE, FilterType::Triangle)
+    } else {
+        square
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{QRCodeStylingOptions, QROptions};
+    use crate::types::ErrorCorrectionLevel;
+
+    /// Left half black, right half white, 90×60 so cropping is exercised.
+    fn picture() -> Vec<u8> {
+        let image = image::RgbImage::from_fn(90, 60, |x, _| {
+            if x < 45 {
+                image::Rgb([0, 0, 0])
+            } else {
+                image::Rgb([255, 255, 255])
+            }
+        });
+        let mut png = Vec::new();
+        DynamicImage::ImageRgb8(image)
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .unwrap();
+        png
+    }
+
+    fn plan(error_budget: f64) -> (QRMatrix, Halftone) {
+        let options = QRCodeStylingOptions {
+            data: "https://example.com/halftone".to_string(),
+            qr_options: QROptions::new().with_error_correction_level(ErrorCorrectionLevel::H),
+            ..Default::default()
+        };
+        let matrix = QRMatrix::new(&options.data, &options.qr_options).unwrap();
+        let coverage = crate::analysis::coverage(&options, &matrix).unwrap();
+        let halftone = HalftoneOptions::new(picture()).with_error_budget(error_budget);
+        let plan = Halftone::new(&halftone, &matrix, &coverage).unwrap();
+        (matrix, plan)
+    }
+
+    #[test]
+    fn test_zero_budget_keeps_codewords() {
+        let (matrix, plan) = plan(0.0);
+        let layout = matrix.layout();
+        for row in 0..matrix.size() {
+            for col in 0..matrix.size() {
+                if layout.kind(row, col) != ModuleKind::Remainder {
+                    assert_eq!(plan.is_dark(row, col), matrix.is_dark(row, col));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_budget_respected_and_matches_picture() {
+        let (matrix, plan) = plan(1.0);
+        let layout = matrix.layout();
+        let mut changed: Vec<std::collections::HashSet<usize>> =
+            vec![Default::default(); layout.blocks().len()];
+        let (mut before, mut after) = (0, 0);
+        for row in 0..matrix.size() {
+            for col in 0..matrix.size() {
+                let Some(cw) = layout.codeword(row, col) else {
+                    continue;
+                };
+                let wants_dark = col < matrix.size() / 2;
+                before += (matrix.is_dark(row, col) == wants_dark) as usize;
+                after += (plan.is_dark(row, col) == wants_dark) as usize;
+                if plan.is_dark(row, col) != matrix.is_dark(row, col) {
+                    changed[cw.block].insert(cw.index);
+                }
+            }
+        }
+        assert!(changed.iter().any(|block| !block.is_empty()));
+        assert!(after > before);
+        for (block, info) in layout.blocks().iter().enumerate() {
+            assert!(changed[block].len() <= info.correctable);
+        }
+    }
+
+    #[test]
+    fn test_bad_picture() {
+        let options = QRCodeStylingOptions::default();
+        let matrix = QRMatrix::new("halftone", &options.qr_options).unwrap();
+        let coverage = crate::analysis::coverage(&options, &matrix).unwrap();
+        let halftone = HalftoneOptions::new(vec![1, 2, 3]);
+        assert!(Halftone::new(&halftone, &matrix, &coverage).is_err());
+    }
+
+    #[test]
+    fn test_halftone_decode() {
+        use crate::config::DotsOptions;
+        use crate::types::{DotType, OutputFormat};
+        use crate::QRCodeStyling;
+
+        let build = |dot_type: DotType| {
+            QRCodeStyling::builder()
+                .data("https://example.com/halftone")
+                .size(400)
+                .qr_options(QROptions::new().with_error_correction_level(ErrorCorrectionLevel::H))
+                .dots_options(DotsOptions::new(dot_type))
+                .halftone(HalftoneOptions::new(picture()))
+                .build()
+                .unwrap()
+        };
+        assert!(build(DotType::Square)
+            .render_svg()
+            .unwrap()
+            .contains("<image "));
+        // Round dots leave the most of the picture showing.
+        let qr = build(DotType::Dots);
+        let report = qr.verify().unwrap();
+        assert!(report.is_ok(), "{:?}", report.error);
+        assert!(!qr.render(OutputFormat::Pdf).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,538 @@
+//! Module layout of a QR symbol: function patterns, Reed-Solomon blocks and
+//! where every codeword bit is placed.
+
+use crate::error::{QRError, Result};
+use crate::types::ErrorCorrectionLevel;
+
+/// Role of a single module in the symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ModuleKind {
+    /// 7×7 finder pattern.
+    Finder,
+    /// Light border between a finder pattern and the data area.
+    Separator,
+    /// Alternating timing row or column.
+    Timing,
+    /// 5×5 alignment pattern.
+    Alignment,
+    /// Format information (error correction level and mask).
+    FormatInfo,
+    /// Version information (version 7 and up).
+    VersionInfo,
+    /// The always-dark module beside the bottom-left format information.
+    DarkModule,
+    /// Bit of a data codeword.
+    Data,
+    /// Bit of an error correction codeword.
+    ErrorCorrection,
+    /// Leftover bit that belongs to no codeword.
+    Remainder,
+}
+
+impl ModuleKind {
+    /// Returns true for fixed patterns that carry no codeword bits.
+    pub fn is_function(&self) -> bool {
+        !matches!(
+            self,
+            ModuleKind::Data | ModuleKind::ErrorCorrection | ModuleKind::Remainder
+        )
+    }
+}
+
+/// One Reed-Solomon block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RsBlock {
+    /// Data codewords in the block.
+    pub data_codewords: usize,
+    /// Error correction codewords in the block.
+    pub ec_codewords: usize,
+    /// Codeword errors the block can correct.
+    pub correctable: usize,
+}
+
+/// Location of a module's bit within the Reed-Solomon blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CodewordRef {
+    /// Block index.
+    pub block: usize,
+    /// Codeword index within the block, data codewords first.
+    pub index: usize,
+    /// Bit within the codeword, 7 being the most significant.
+    pub bit: u8,
+    /// The codeword is an error correction codeword.
+    pub is_ec: bool,
+}
+
+/// Error correction codewords per block, indexed by version - 1.
+const EC_CODEWORDS_PER_BLOCK: [[u8; 40]; 4] = [
+    [
+        7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28, 28, 28, 30,
+        30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28, 28,
+        28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+    ],
+    [
+        13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30, 28, 30, 30,
+        30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28, 30, 24, 30,
+        30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+];
+
+/// Number of Reed-Solomon blocks, indexed by version - 1.
+const NUM_BLOCKS: [[u8; 40]; 4] = [
+    [
+        1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9, 10, 12, 12, 12, 13,
+        14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25,
+    ],
+    [
+        1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21, 23,
+        25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49,
+    ],
+    [
+        1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20, 23, 23, 25, 27, 29,
+        34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62, 65, 68,
+    ],
+    [
+        1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25, 25, 34, 30, 32, 35,
+        37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81,
+    ],
+];
+
+/// Full module layout of one version and error correction level.
+#[derive(Debug, Clone)]
+pub struct QRLayout {
+    version: u8,
+    level: ErrorCorrectionLevel,
+    size: usize,
+    kinds: Vec<ModuleKind>,
+    codewords: Vec<Option<CodewordRef>>,
+    blocks: Vec<RsBlock>,
+}
+
+impl QRLayout {
+    /// Build the layout for `version` (1-40) at `level`.
+    pub fn new(version: u8, level: ErrorCorrectionLevel) -> Result<Self> {
+        if !(1..=40).contains(&version) {
+            return Err(QRError::InvalidVersion(version));
+        }
+        let size = version as usize * 4 + 17;
+        let mut layout = Self {
+            version,
+            level,
+            size,
+            kinds: vec![ModuleKind::Remainder; size * size],
+            codewords: vec![None; size * size],
+            blocks: rs_blocks(version, level),
+        };
+        layout.mark_function_patterns();
+        layout.place_codewords();
+        Ok(layout)
+    }
+
+    /// QR code version (1-40).
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Error correction level.
+    pub fn error_correction_level(&self) -> ErrorCorrectionLevel {
+        self.level
+    }
+
+    /// Modules per side.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Reed-Solomon blocks in codeword order, short blocks first.
+    pub fn blocks(&self) -> &[RsBlock] {
+        &self.blocks
+    }
+
+    /// Role of the module at (row, col).
+    pub fn kind(&self, row: usize, col: usize) -> ModuleKind {
+        self.kinds[row * self.size + col]
+    }
+
+    /// Codeword bit stored at (row, col), if any.
+    pub fn codeword(&self, row: usize, col: usize) -> Option<CodewordRef> {
+        self.codewords[row * self.size + col]
+    }
+
+    /// Row/column coordinates of the alignment pattern centers.
+    pub fn alignment_positions(&self) -> Vec<usize> {
+        alignment_positions(self.version)
+    }
+
+    /// (row, col) of each alignment pattern center, skipping the
+    /// combinations that would overlap finder patterns.
+    pub fn alignment_centers(&self) -> Vec<(usize, usize)> {
+        let positions = alignment_positions(self.version);
+        let last = positions.len().saturating_sub(1);
+        let mut centers = Vec::new();
+        for (i, &row) in positions.iter().enumerate() {
+            for (j, &col) in positions.iter().enumerate() {
+                if !((i == 0 && (j == 0 || j == last)) || (i == last && j == 0)) {
+                    centers.push((row, col));
+                }
+            }
+        }
+        centers
+    }
+
+    /// (block, index) of each codeword in transmission order.
+    pub(crate) fn codeword_order(&self) -> Vec<(usize, usize)> {
+        interleaved_order(&self.blocks)
+    }
+
+    fn set(&mut self, row: usize, col: usize, kind: ModuleKind) {
+        self.kinds[row * self.size + col] = kind;
+    }
+
+    fn mark_function_patterns(&mut self) {
+        let size = self.size;
+
+        for (top, left) in [(0, 0), (0, size - 7), (size - 7, 0)] {
+            for i in 0..8 {
+                // Separators run along the inner edges of each finder.
+                let row = if top == 0 { 7 } else { top - 1 };
+                let col = if left == 0 { 7 } else { left - 1 };
+                self.set(
+                    row,
+                    if left == 0 { i } else { left - 1 + i },
+                    ModuleKind::Separator,
+                );
+                self.set(
+                    if top == 0 { i } else { top - 1 + i },
+                    col,
+                    ModuleKind::Separator,
+                );
+            }
+            for r in 0..7 {
+                for c in 0..7 {
+                    self.set(top + r, left + c, ModuleKind::Finder);
+                }
+            }
+        }
+
+        for i in 8..size - 8 {
+            self.set(6, i, ModuleKind::Timing);
+            self.set(i, 6, ModuleKind::Timing);
+        }
+
+        for (row, col) in self.alignment_centers() {
+            for r in row - 2..=row + 2 {
+                for c in col - 2..=col + 2 {
+                    self.set(r, c, ModuleKind::Alignment);
+                }
+            }
+        }
+
+        for i in 0..9 {
+            if i != 6 {
+                self.set(8, i, ModuleKind::FormatInfo);
+                self.set(i, 8, ModuleKind::FormatInfo);
+            }
+        }
+        for i in 0..8 {
+            self.set(8, size - 1 - i, ModuleKind::FormatInfo);
+        }
+        for i in 0..7 {
+            self.set(size - 1 - i, 8, ModuleKind::FormatInfo);
+        }
+        self.set(size - 8, 8, ModuleKind::DarkModule);
+
+        if self.version >= 7 {
+            for i in 0..6 {
+                for j in 0..3 {
+                    self.set(i, size - 11 + j, ModuleKind::VersionInfo);
+                    self.set(size - 11 + j, i, ModuleKind::VersionInfo);
+                }
+            }
+        }
+    }
+
+    /// Walk the zigzag placement path and record which codeword bit lands
+    /// on every non-function module.
+    fn place_codewords(&mut self) {
+        let order = interleaved_order(&self.blocks);
+        let total_bits = order.len() * 8;
+        let size = self.size as isize;
+
+        let mut bit = 0;
+        let mut right = size - 1;
+        while right >= 1 {
+            if right == 6 {
+                right = 5;
+            }
+            let upward = (right + 1) & 2 == 0;
+            for vert in 0..size {
+                let row = if upward { size - 1 - vert } else { vert } as usize;
+                for j in 0..2 {
+                    let col = (right - j) as usize;
+                    if self.kind(row, col).is_function() {
+                        continue;
+                    }
+                    if bit < total_bits {
+                        let (block, index) = order[bit / 8];
+                        let is_ec = index >= self.blocks[block].data_codewords;
+                        self.set(
+                            row,
+                            col,
+                            if is_ec {
+                                ModuleKind::ErrorCorrection
+                            } else {
+                                ModuleKind::Data
+                            },
+                        );
+                        self.codewords[row * self.size + col] = Some(CodewordRef {
+                            block,
+                            index,
+                            bit: 7 - (bit % 8) as u8,
+                            is_ec,
+                        });
+                    }
+                    bit += 1;
+                }
+            }
+            right -= 2;
+        }
+    }
+}
+
+/// Whether data mask pattern `mask` (0-7) inverts the module at (row, col).
+pub(crate) fn mask_bit(mask: u8, row: usize, col: usize) -> bool {
+    let (r, c) = (row, col);
+    match mask {
+        0 => (r + c) % 2 == 0,
+        1 => r % 2 == 0,
+        2 => c % 3 == 0,
+        3 => (r + c) % 3 == 0,
+        4 => (r / 2 + c / 3) % 2 == 0,
+        5 => (r * c) % 2 + (r * c) % 3 == 0,
+        6 => ((r * c) % 2 + (r * c) % 3) % 2 == 0,
+        _ => ((r + c) % 2 + (r * c) % 3) % 2 == 0,
+    }
+}
+
+fn level_index(level: ErrorCorrectionLevel) -> usize {
+    match level {
+        ErrorCorrectionLevel::L => 0,
+        ErrorCorrectionLevel::M => 1,
+        ErrorCorrectionLevel::Q => 2,
+        ErrorCorrectionLevel::H => 3,
+    }
+}
+
+/// Alignment pattern center coordinates for `version`.
+fn alignment_positions(version: u8) -> Vec<usize> {
+    if version == 1 {
+        return Vec::new();
+    }
+    let version = version as usize;
+    let size = version * 4 + 17;
+    let count = version / 7 + 2;
+    let step = (version * 8 + count * 3 + 5) / (count * 4 - 4) * 2;
+    let mut positions: Vec<usize> = (0..count - 1).map(|i| size - 7 - i * step).collect();
+    positions.push(6);
+    positions.reverse();
+    positions
+}
+
+/// Modules available for codewords (and remainder bits).
+fn raw_data_modules(version: u8) -> usize {
+    let v = version as usize;
+    let mut modules = (16 * v + 128) * v + 64;
+    if v >= 2 {
+        let count = v / 7 + 2;
+        modules -= (25 * count - 10) * count - 55;
+        if v >= 7 {
+            modules -= 36;
+        }
+    }
+    modules
+}
+
+fn rs_blocks(version: u8, level: ErrorCorrectionLevel) -> Vec<RsBlock> {
+    let level_idx = level_index(level);
+    let ec = EC_CODEWORDS_PER_BLOCK[level_idx][version as usize - 1] as usize;
+    let count = NUM_BLOCKS[level_idx][version as usize - 1] as usize;
+    let raw = raw_data_modules(version) / 8;
+    let short_len = raw / count;
+    let short_count = count - raw % count;
+
+    // Small codes reserve a few EC codewords for misdecode protection
+    // instead of correction (ISO/IEC 18004 table 9, "p").
+    let reserved = match (version, level) {
+        (1, ErrorCorrectionLevel::L) => 3,
+        (1, ErrorCorrectionLevel::M) | (2, ErrorCorrectionLevel::L) => 2,
+        (1, _) | (3, ErrorCorrectionLevel::L) => 1,
+        _ => 0,
+    };
+
+    (0..count)
+        .map(|i| {
+            let len = if i < short_count {
+                short_len
+            } else {
+                short_len + 1
+            };
+            RsBlock {
+                data_codewords: len - ec,
+                ec_codewords: ec,
+                correctable: (ec - reserved) / 2,
+            }
+        })
+        .collect()
+}
+
+/// (block, index) of each codeword in transmission order.
+fn interleaved_order(blocks: &[RsBlock]) -> Vec<(usize, usize)> {
+    let mut order = Vec::new();
+    let max_data = blocks.iter().map(|b| b.data_codewords).max().unwrap_or(0);
+    for i in 0..max_data {
+        for (b, block) in blocks.iter().enumerate() {
+            if i < block.data_codewords {
+                order.push((b, i));
+            }
+        }
+    }
+    let ec = blocks.first().map_or(0, |b| b.ec_codewords);
+    for i in 0..ec {
+        for (b, block) in blocks.iter().enumerate() {
+            order.push((b, block.data_codewords + i));
+        }
+    }
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qrcode::canvas::{is_functional, Canvas};
+    use qrcode::Version;
+
+    const LEVELS: [ErrorCorrectionLevel; 4] = [
+        ErrorCorrectionLevel::L,
+        ErrorCorrectionLevel::M,
+        ErrorCorrectionLevel::Q,
+        ErrorCorrectionLevel::H,
+    ];
+
+    #[test]
+    fn test_function_modules_match_qrcode() {
+        for version in 1..=40u8 {
+            let layout = QRLayout::new(version, ErrorCorrectionLevel::M).unwrap();
+            let width = layout.size() as i16;
+            for row in 0..layout.size() {
+                for col in 0..layout.size() {
+                    // qrcode's helper leaves version information out.
+                    let kind = layout.kind(row, col);
+                    assert_eq!(
+                        kind.is_function() && kind != ModuleKind::VersionInfo,
+                        is_functional(
+                            Version::Normal(version as i16),
+                            width,
+                            col as i16,
+                            row as i16
+                        ),
+                        "version {} at ({}, {})",
+                        version,
+                        row,
+                        col
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_blocks_match_qrcode() {
+        for version in 1..=40u8 {
+            for level in LEVELS {
+                let blocks = rs_blocks(version, level);
+                let qr_version = Version::Normal(version as i16);
+                let ec_level = level.to_qrcode_level();
+
+                let data_bits = qrcode::bits::Bits::new(qr_version)
+                    .max_len(ec_level)
+                    .unwrap();
+                let data: Vec<u8> = (0..data_bits / 8).map(|i| (i * 31 + 7) as u8).collect();
+                assert_eq!(
+                    blocks.iter().map(|b| b.data_codewords).sum::<usize>(),
+                    data.len()
+                );
+
+                let correctable: usize = blocks.iter().map(|b| b.correctable).sum();
+                assert_eq!(
+                    correctable,
+                    qrcode::ec::max_allowed_errors(qr_version, ec_level).unwrap()
+                );
+
+                // Recompute each block's EC and interleave it like the encoder.
+                let (_, expected_ec) =
+                    qrcode::ec::construct_codewords(&data, qr_version, ec_level).unwrap();
+                let mut offset = 0;
+                let ecs: Vec<Vec<u8>> = blocks
+                    .iter()
+                    .map(|b| {
+                        let block = &data[offset..offset + b.data_codewords];
+                        offset += b.data_codewords;
+                        qrcode::ec::create_error_correction_code(block, b.ec_codewords)
+                    })
+                    .collect();
+                let ec_len = blocks[0].ec_codewords;
+                let interleaved: Vec<u8> = (0..ec_len)
+                    .flat_map(|i| ecs.iter().map(move |ec| ec[i]))
+                    .collect();
+                assert_eq!(interleaved, expected_ec, "version {} {:?}", version, level);
+            }
+        }
+    }
+
+    #[test]
+    fn test_placement_matches_qrcode() {
+        for version in [1u8, 2, 7, 10, 22, 40] {
+            for level in LEVELS {
+                let layout = QRLayout::new(version, level).unwrap();
+                let order = interleaved_order(layout.blocks());
+                let stream: Vec<u8> = (0..order.len()).map(|i| (i * 73 + 11) as u8).collect();
+
+                let mut canvas =
+                    Canvas::new(Version::Normal(version as i16), level.to_qrcode_level());
+                canvas.draw_all_functional_patterns();
+                canvas.draw_data(&stream, &[]);
+
+                let position: std::collections::HashMap<(usize, usize), usize> =
+                    order.iter().enumerate().map(|(i, &key)| (key, i)).collect();
+                for row in 0..layout.size() {
+                    for col in 0..layout.size() {
+                        let Some(cw) = layout.codeword(row, col) else {
+                            continue;
+                        };
+                        let byte = stream[position[&(cw.block, cw.index)]];
+                        let expected = (byte >> cw.bit) & 1 == 1;
+                        assert_eq!(
+                            canvas.get(col as i16, row as i16).is_dark(),
+                            expected,
+                            "version {} {:?} at ({}, {})",
+                            version,
+                            level,
+                            row,
+                            col
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
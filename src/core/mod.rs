@@ -1,7 +1,12 @@
 //! Core QR code generation and styling.
 
+mod halftone;
+mod layout;
 mod qr_matrix;
 mod qr_code_styling;
 
+pub(crate) use halftone::Halftone;
+pub(crate) use layout::mask_bit;
+pub use layout::{CodewordRef, ModuleKind, QRLayout, RsBlock};
 pub use qr_matrix::QRMatrix;
 pub use qr_code_styling::QRCodeStyling;
@@ -4,10 +4,14 @@ use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 
+use crate::analysis::{
+    self, ContrastCheck, ContrastReport, CoverageReport, RobustnessOptions, RobustnessReport,
+    VerifyReport,
+};
 use crate::config::{QRCodeStylingBuilder, QRCodeStylingOptions};
 use crate::core::QRMatrix;
 use crate::error::Result;
-use crate::rendering::{PdfRenderer, RasterRenderer, SvgRenderer};
+use crate::rendering::{PdfRenderer, RasterRenderer, SvgRenderer, EFFECT_RASTER_SCALE};
 use crate::types::OutputFormat;
 
 /// Main QR code styling struct.
@@ -31,6 +35,7 @@ use crate::types::OutputFormat;
 pub struct QRCodeStyling {
     options: QRCodeStylingOptions,
     matrix: QRMatrix,
+    contrast_warnings: Vec<ContrastCheck>,
 }
 
 impl QRCodeStylingBuilder {
@@ -48,16 +53,54 @@ impl QRCodeStyling {
     }
 
     /// Create a new QRCodeStyling with the given options.
-    pub fn new(options: QRCodeStylingOptions) -> Result<Self> {
+    ///
+    /// Fails, warns or adjusts colors first according to
+    /// [`ContrastOptions::policy`](crate::config::ContrastOptions::policy),
+    /// and fails if a strict-coverage logo would make the code unreadable
+    /// or a custom shape has nothing to draw it.
+    pub fn new(mut options: QRCodeStylingOptions) -> Result<Self> {
+        options.validate()?;
+        let contrast_warnings = analysis::enforce_contrast(&mut options)?;
         let matrix = QRMatrix::new(&options.data, &options.qr_options)?;
+        analysis::coverage(&options, &matrix)?;
 
-        Ok(Self { options, matrix })
+        Ok(Self {
+            options,
+            matrix,
+            contrast_warnings,
+        })
+    }
+
+    /// Read an existing QR code from a PNG, JPEG or WebP image and restyle it.
+    ///
+    /// The version, error correction level and mask are taken from the
+    /// image, and the original codewords are kept when they read back
+    /// without errors, so the restyled code carries exactly the same bits.
+    /// Everything else comes from `style`; its data is replaced.
+    pub fn from_image(bytes: &[u8], mut style: QRCodeStylingOptions) -> Result<Self> {
+        let image = image::load_from_memory(bytes)?.to_luma8();
+        let (decoded, matrix) = analysis::import(&image)?;
+
+        style.data = decoded.data;
+        style.qr_options.type_number = decoded.version;
+        style.qr_options.error_correction_level = decoded.error_correction_level;
+        style.qr_options.mask_pattern = Some(decoded.mask);
+        style.validate()?;
+        let contrast_warnings = analysis::enforce_contrast(&mut style)?;
+        analysis::coverage(&style, &matrix)?;
+
+        Ok(Self {
+            options: style,
+            matrix,
+            contrast_warnings,
+        })
     }
 
     /// Update the data and regenerate the QR code.
     pub fn update(&mut self, data: &str) -> Result<&mut Self> {
         self.options.data = data.to_string();
         self.matrix = QRMatrix::new(&self.options.data, &self.options.qr_options)?;
+        analysis::coverage(&self.options, &self.matrix)?;
         Ok(self)
     }
 
@@ -81,11 +124,50 @@ impl QRCodeStyling {
             OutputFormat::Pdf => {
                 // Convert SVG directly to PDF (vector quality preserved)
                 let svg = self.render_svg()?;
-                PdfRenderer::render_from_svg(&svg, self.options.width, self.options.height)
+                if self.options.effects.is_empty() {
+                    PdfRenderer::render_from_svg(&svg, self.options.width, self.options.height)
+                } else {
+                    PdfRenderer::render_with_raster_scale(&svg, EFFECT_RASTER_SCALE)
+                }
             }
         }
     }
 
+    /// Check every foreground fill against the background.
+    pub fn contrast_report(&self) -> ContrastReport {
+        analysis::analyze_contrast(&self.options)
+    }
+
+    /// Failing contrast checks found when the code was built under
+    /// [`ContrastPolicy::Warn`](crate::config::ContrastPolicy::Warn).
+    pub fn contrast_warnings(&self) -> &[ContrastCheck] {
+        &self.contrast_warnings
+    }
+
+    /// Codewords the logo hides in each Reed-Solomon block, compared with
+    /// what the block can correct.
+    pub fn coverage_report(&self) -> Result<CoverageReport> {
+        analysis::coverage(&self.options, &self.matrix)
+    }
+
+    /// Rasterize the styled code, decode it and compare with the input data.
+    ///
+    /// Use this to catch style combinations (dot types, gradients, logos,
+    /// circle shape) that scanners cannot read before the code is printed.
+    pub fn verify(&self) -> Result<VerifyReport> {
+        analysis::verify(&self.options, &self.matrix)
+    }
+
+    /// Shrink and degrade the rendered code to find where it stops decoding.
+    ///
+    /// Reports the smallest module size in pixels that still decodes and,
+    /// for each simulated degradation, the worst level that still decodes.
+    /// A level only counts when the detector itself finds the code; reads
+    /// that need grid sampling are flagged in [`LevelResult::grid_sampled`](crate::analysis::LevelResult::grid_sampled).
+    pub fn robustness(&self, options: &RobustnessOptions) -> Result<RobustnessReport> {
+        analysis::robustness(&self.options, &self.matrix, options)
+    }
+
     /// Save the QR code to a file.
     pub fn save<P: AsRef<Path>>(&self, path: P, format: OutputFormat) -> Result<()> {
         let data = self.render(format)?;
@@ -111,7 +193,10 @@ impl QRCodeStyling {
 
     /// Regenerate the QR matrix (call after modifying options).
     pub fn regenerate(&mut self) -> Result<()> {
+        self.options.validate()?;
+        self.contrast_warnings = analysis::enforce_contrast(&mut self.options)?;
         self.matrix = QRMatrix::new(&self.options.data, &self.options.qr_options)?;
+        analysis::coverage(&self.options, &self.matrix)?;
         Ok(())
     }
 }
@@ -177,6 +262,35 @@ mod tests {
         assert!(svg.contains("circle"));
     }
 
+    #[test]
+    fn test_from_image() {
+        use crate::config::QROptions;
+
+        let flat = QRCodeStyling::builder()
+            .data("https://example.com/brand")
+            .qr_options(QROptions::new().with_mask_pattern(4))
+            .build()
+            .unwrap();
+        let png = flat.render(OutputFormat::Png).unwrap();
+
+        let style = QRCodeStylingOptions {
+            dots_options: DotsOptions::new(DotType::Rounded),
+            ..Default::default()
+        };
+        let qr = QRCodeStyling::from_image(&png, style).unwrap();
+
+        assert_eq!(qr.options.data, "https://example.com/brand");
+        assert_eq!(qr.options.qr_options.mask_pattern, Some(4));
+        assert_eq!(qr.options.dots_options.dot_type, DotType::Rounded);
+        let size = flat.matrix.size();
+        assert_eq!(qr.matrix.size(), size);
+        for row in 0..size {
+            for col in 0..size {
+                assert_eq!(qr.matrix.is_dark(row, col), flat.matrix.is_dark(row, col));
+            }
+        }
+    }
+
     #[test]
     fn test_render_png() {
         let qr = QRCodeStyling::builder()
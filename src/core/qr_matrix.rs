@@ -1,7 +1,10 @@
 //! QR code matrix wrapper providing neighbor lookup functionality.
 
 use crate::config::QROptions;
+use crate::core::QRLayout;
 use crate::error::{QRError, Result};
+use crate::types::ErrorCorrectionLevel;
+use qrcode::canvas::{Canvas, MaskPattern};
 use qrcode::{QrCode, Version};
 
 /// Wrapper around the QR code matrix providing efficient module access.
@@ -11,6 +14,8 @@ pub struct QRMatrix {
     modules: Vec<bool>,
     /// Size of the QR code (number of modules per side).
     size: usize,
+    /// Error correction level the code was built with.
+    error_correction_level: ErrorCorrectionLevel,
 }
 
 impl QRMatrix {
@@ -25,6 +30,27 @@ impl QRMatrix {
             Some(Version::Normal(options.type_number as i16))
         };
 
+        if let Some(mask) = options.mask_pattern {
+            if mask > 7 {
+                return Err(QRError::InvalidMaskPattern(mask));
+            }
+            let bits = match version {
+                Some(v) => {
+                    let mut bits = qrcode::bits::Bits::new(v);
+                    bits.push_optimal_data(data.as_bytes())
+                        .map_err(generation_error)?;
+                    bits.push_terminator(ec_level).map_err(generation_error)?;
+                    bits
+                }
+                None => qrcode::bits::encode_auto(data.as_bytes(), ec_level)
+                    .map_err(generation_error)?,
+            };
+            let version = bits.version();
+            let (data, ec) = qrcode::ec::construct_codewords(&bits.into_bytes(), version, ec_level)
+                .map_err(generation_error)?;
+            return Self::from_codewords(version, options.error_correction_level, mask, &data, &ec);
+        }
+
         // Build the QR code
         let qr = if let Some(v) = version {
             QrCode::with_version(data.as_bytes(), v, ec_level)
@@ -34,7 +60,7 @@ impl QRMatrix {
                 .map_err(|e| QRError::QRGenerationError(e.to_string()))?
         };
 
-        let size = qr.width() as usize;
+        let size = qr.width();
         let mut modules = Vec::with_capacity(size * size);
 
         // Convert to flat array for O(1) access
@@ -45,7 +71,38 @@ impl QRMatrix {
             }
         }
 
-        Ok(Self { modules, size })
+        Ok(Self {
+            modules,
+            size,
+            error_correction_level: options.error_correction_level,
+        })
+    }
+
+    /// Build a matrix from interleaved data and error correction codewords
+    /// with a fixed mask pattern.
+    pub(crate) fn from_codewords(
+        version: Version,
+        error_correction_level: ErrorCorrectionLevel,
+        mask: u8,
+        data: &[u8],
+        ec: &[u8],
+    ) -> Result<Self> {
+        let mut canvas = Canvas::new(version, error_correction_level.to_qrcode_level());
+        canvas.draw_all_functional_patterns();
+        canvas.draw_data(data, ec);
+        canvas.apply_mask(mask_pattern(mask));
+
+        let size = version.width() as usize;
+        let modules = canvas
+            .into_colors()
+            .into_iter()
+            .map(|c| c == qrcode::Color::Dark)
+            .collect();
+        Ok(Self {
+            modules,
+            size,
+            error_correction_level,
+        })
     }
 
     /// Get the size (width/height) of the QR code in modules.
@@ -54,6 +111,24 @@ impl QRMatrix {
         self.size
     }
 
+    /// Get the QR code version (1-40) derived from the size.
+    #[inline]
+    pub fn version(&self) -> u8 {
+        ((self.size - 17) / 4) as u8
+    }
+
+    /// Get the error correction level.
+    #[inline]
+    pub fn error_correction_level(&self) -> ErrorCorrectionLevel {
+        self.error_correction_level
+    }
+
+    /// Build the module layout (function patterns and codeword placement).
+    pub fn layout(&self) -> QRLayout {
+        QRLayout::new(self.version(), self.error_correction_level)
+            .expect("matrix size always maps to a valid version")
+    }
+
     /// Get the module count (same as size for compatibility).
     #[inline]
     pub fn module_count(&self) -> usize {
@@ -155,7 +230,7 @@ impl QRMatrix {
         let check_inner = |r: usize, c: usize, start_r: usize, start_c: usize| -> bool {
             let local_r = r - start_r;
             let local_c = c - start_c;
-            local_r >= 2 && local_r <= 4 && local_c >= 2 && local_c <= 4
+            (2..=4).contains(&local_r) && (2..=4).contains(&local_c)
         };
 
         // Top-left
@@ -177,6 +252,23 @@ impl QRMatrix {
     }
 }
 
+fn generation_error(error: qrcode::types::QrError) -> QRError {
+    QRError::QRGenerationError(error.to_string())
+}
+
+fn mask_pattern(mask: u8) -> MaskPattern {
+    match mask {
+        0 => MaskPattern::Checkerboard,
+        1 => MaskPattern::HorizontalLines,
+        2 => MaskPattern::VerticalLines,
+        3 => MaskPattern::DiagonalLines,
+        4 => MaskPattern::LargeCheckerboard,
+        5 => MaskPattern::Fields,
+        6 => MaskPattern::Diamonds,
+        _ => MaskPattern::Meadow,
+    }
+}
+
 /// Square mask for corner squares (7x7 pattern).
 /// 1 = part of outer square border, 0 = not part of border
 #[allow(dead_code)]
@@ -214,6 +306,16 @@ mod tests {
         assert!(matrix.size() >= 21); // Minimum QR code size
     }
 
+    #[test]
+    fn test_invalid_mask_pattern() {
+        let options = QROptions::default().with_mask_pattern(8);
+        assert!(matches!(
+            QRMatrix::new("Hello", &options),
+            Err(QRError::InvalidMaskPattern(8))
+        ));
+        assert!(QRMatrix::new("Hello", &QROptions::default().with_mask_pattern(7)).is_ok());
+    }
+
     #[test]
     fn test_is_dark() {
         let options = QROptions::default();
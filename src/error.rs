@@ -20,6 +20,10 @@ pub enum QRError {
     #[error("Invalid QR code version: {0}")]
     InvalidVersion(u8),
 
+    /// Mask pattern outside 0-7.
+    #[error("Invalid mask pattern: {0}")]
+    InvalidMaskPattern(u8),
+
     /// Canvas dimensions are too small for the QR code.
     #[error("Canvas dimensions too small: {width}x{height}")]
     CanvasTooSmall { width: u32, height: u32 },
@@ -32,6 +36,14 @@ pub enum QRError {
     #[error("Gradient must have at least one color stop")]
     EmptyGradient,
 
+    /// No preset is registered under the given name.
+    #[error("Unknown preset: {0}")]
+    UnknownPreset(String),
+
+    /// Options that cannot be drawn as configured.
+    #[error("Invalid options: {0}")]
+    InvalidOptions(String),
+
     /// Failed to load an image.
     #[error("Failed to load image: {0}")]
     ImageLoadError(String),
@@ -52,7 +64,72 @@ pub enum QRError {
     #[error("QR code generation failed: {0}")]
     QRGenerationError(String),
 
+    /// No readable QR code was found in an input image.
+    #[error("Failed to decode QR code: {0}")]
+    DecodeError(String),
+
     /// SVG rendering error.
     #[error("SVG rendering error: {0}")]
     SvgError(String),
+
+    /// A foreground fill does not contrast enough with the background.
+    #[error("Insufficient contrast for {target}: {ratio:.2}:1 is below {min_ratio:.2}:1")]
+    InsufficientContrast {
+        target: String,
+        ratio: f64,
+        min_ratio: f64,
+    },
+
+    /// The logo hides more codewords than a Reed-Solomon block can correct.
+    #[error(
+        "Logo hides {damaged} codewords of block {block}, which can only correct {correctable}"
+    )]
+    LogoCoverage {
+        block: usize,
+        damaged: usize,
+        correctable: usize,
+    },
+
+    /// A decoded payload string could not be parsed.
+    #[error("Invalid payload: {0}")]
+    InvalidPayload(#[from] PayloadError),
+}
+
+/// Errors that can occur while parsing a QR code payload string.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum PayloadError {
+    /// The payload does not start with any known prefix.
+    #[error("Unrecognized payload format")]
+    UnknownFormat,
+
+    /// A required field is absent.
+    #[error("{payload} payload is missing required field `{field}`")]
+    MissingField {
+        payload: &'static str,
+        field: &'static str,
+    },
+
+    /// A field is present but its value is not acceptable.
+    #[error("{payload} payload has invalid `{field}` value {value:?}: {reason}")]
+    InvalidField {
+        payload: &'static str,
+        field: &'static str,
+        value: String,
+        reason: &'static str,
+    },
+
+    /// The overall payload structure is broken.
+    #[error("{payload} payload is malformed: {reason}")]
+    Malformed {
+        payload: &'static str,
+        reason: String,
+    },
+
+    /// A checksum embedded in the payload does not match its contents.
+    #[error("{payload} checksum mismatch: expected {expected}, found {found}")]
+    ChecksumMismatch {
+        payload: &'static str,
+        expected: String,
+        found: String,
+    },
 }
@@ -1,24 +1,43 @@
 //! QR corner dot drawer implementation.
 
-use crate::figures::traits::{rotate_transform, svg_circle, svg_rect};
+use crate::figures::traits::{
+    polygon_data, rotate_transform, rounded_rect_data, svg_circle, svg_path, svg_rect,
+};
 use crate::types::CornerDotType;
 
 /// QR code corner dot drawer (center of finder patterns).
 pub struct QRCornerDot {
     dot_type: CornerDotType,
+    radius: f64,
 }
 
 impl QRCornerDot {
     /// Create a new corner dot drawer with the specified type.
     pub fn new(dot_type: CornerDotType) -> Self {
-        Self { dot_type }
+        Self {
+            dot_type,
+            radius: 0.5,
+        }
+    }
+
+    /// Set the corner radius as a fraction of half the side (0.0 to 1.0).
+    pub fn with_radius(mut self, radius: f64) -> Self {
+        self.radius = radius.clamp(0.0, 1.0);
+        self
     }
 
     /// Draw the corner dot.
     pub fn draw(&self, x: f64, y: f64, size: f64, rotation: f64) -> String {
         match self.dot_type {
             CornerDotType::Dot => self.draw_dot(x, y, size, rotation),
-            CornerDotType::Square => self.draw_square(x, y, size, rotation),
+            CornerDotType::Square | CornerDotType::Custom => self.draw_square(x, y, size, rotation),
+            CornerDotType::Rounded => self.basic_rounded(x, y, size, rotation, [true; 4]),
+            CornerDotType::Leaf => {
+                self.basic_rounded(x, y, size, rotation, [true, false, true, false])
+            }
+            CornerDotType::Diamond => self.basic_diamond(x, y, size, rotation),
+            CornerDotType::Star => self.basic_star(x, y, size, rotation),
+            CornerDotType::Heart => self.basic_heart(x, y, size, rotation),
         }
     }
 
@@ -39,6 +58,91 @@ impl QRCornerDot {
         svg_rect(x, y, size, size, transform.as_deref())
     }
 
+    /// Draw a square with the chosen corners rounded.
+    fn basic_rounded(
+        &self,
+        x: f64,
+        y: f64,
+        size: f64,
+        rotation: f64,
+        corners: [bool; 4],
+    ) -> String {
+        let transform = rotate_transform(x, y, size, rotation);
+        let r = self.radius * size / 2.0;
+        let radii = corners.map(|rounded| if rounded { r } else { 0.0 });
+        svg_path(
+            &rounded_rect_data(x, y, size, size, radii),
+            None,
+            transform.as_deref(),
+        )
+    }
+
+    /// Draw a diamond touching the middle of each edge.
+    fn basic_diamond(&self, x: f64, y: f64, size: f64, rotation: f64) -> String {
+        let transform = rotate_transform(x, y, size, rotation);
+        let half = size / 2.0;
+        let points = [
+            (x + half, y),
+            (x + size, y + half),
+            (x + half, y + size),
+            (x, y + half),
+        ];
+        svg_path(&polygon_data(&points), None, transform.as_deref())
+    }
+
+    /// Draw a four-pointed star with its points at the middle of each edge.
+    fn basic_star(&self, x: f64, y: f64, size: f64, rotation: f64) -> String {
+        let transform = rotate_transform(x, y, size, rotation);
+        let half = size / 2.0;
+        let (cx, cy) = (x + half, y + half);
+        // Inner corners sit on the diagonals, from a plain diamond (radius 0)
+        // to thin points (radius 1).
+        let inner = half / 2.0 * (1.0 - 0.6 * self.radius);
+        let points = [
+            (cx, y),
+            (cx + inner, cy - inner),
+            (x + size, cy),
+            (cx + inner, cy + inner),
+            (cx, y + size),
+            (cx - inner, cy + inner),
+            (x, cy),
+            (cx - inner, cy - inner),
+        ];
+        svg_path(&polygon_data(&points), None, transform.as_deref())
+    }
+
+    /// Draw a heart widest across the middle, with its point at the bottom.
+    fn basic_heart(&self, x: f64, y: f64, size: f64, rotation: f64) -> String {
+        let transform = rotate_transform(x, y, size, rotation);
+        let half = size / 2.0;
+        let (cx, cy) = (x + half, y + half);
+        // Unit coordinates from -1 to 1 around the center.
+        let p = |ux: f64, uy: f64| format!("{} {}", cx + ux * half, cy + uy * half);
+        let d = format!(
+            "M {} C {} {} {} C {} {} {} C {} {} {} C {} {} {} C {} {} {} C {} {} {} Z",
+            p(0.0, 1.0),
+            p(-0.5, 0.6),
+            p(-1.0, 0.3),
+            p(-1.0, 0.0),
+            p(-1.0, -0.55),
+            p(-0.8, -1.0),
+            p(-0.5, -1.0),
+            p(-0.2, -1.0),
+            p(0.0, -0.85),
+            p(0.0, -0.7),
+            p(0.0, -0.85),
+            p(0.2, -1.0),
+            p(0.5, -1.0),
+            p(0.8, -1.0),
+            p(1.0, -0.55),
+            p(1.0, 0.0),
+            p(1.0, 0.3),
+            p(0.5, 0.6),
+            p(0.0, 1.0)
+        );
+        svg_path(&d, None, transform.as_deref())
+    }
+
     fn draw_dot(&self, x: f64, y: f64, size: f64, rotation: f64) -> String {
         self.basic_dot(x, y, size, rotation)
     }
@@ -67,4 +171,19 @@ mod tests {
         assert!(svg.contains("rect"));
         assert!(svg.contains("width=\"30\""));
     }
+
+    #[test]
+    fn test_draw_new_shapes() {
+        for &dot_type in &CornerDotType::all()[2..7] {
+            let svg = QRCornerDot::new(dot_type).draw(0.0, 0.0, 30.0, 0.0);
+            assert!(svg.starts_with("<path d=\"M "), "{:?}", dot_type);
+        }
+        // Diamond and star reach the middle of every edge.
+        for dot_type in [CornerDotType::Diamond, CornerDotType::Star] {
+            let svg = QRCornerDot::new(dot_type).draw(0.0, 0.0, 30.0, 0.0);
+            for point in ["15 0", "30 15", "15 30", "0 15"] {
+                assert!(svg.contains(point), "{:?} misses {}", dot_type, point);
+            }
+        }
+    }
 }
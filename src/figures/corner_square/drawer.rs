@@ -1,32 +1,63 @@
 //! QR corner square drawer implementation.
 
-use crate::figures::traits::{rotate_transform, svg_path};
+use std::f64::consts::SQRT_2;
+
+use crate::figures::traits::{polygon_data, rotate_transform, rounded_rect_data, svg_path};
 use crate::types::CornerSquareType;
 
 /// QR code corner square drawer.
+///
+/// Draws a ring one module thick around a hollow center, 7 modules wide
+/// for finder patterns unless set otherwise.
 pub struct QRCornerSquare {
     square_type: CornerSquareType,
+    radius: f64,
+    modules: usize,
 }
 
 impl QRCornerSquare {
     /// Create a new corner square drawer with the specified type.
     pub fn new(square_type: CornerSquareType) -> Self {
-        Self { square_type }
+        Self {
+            square_type,
+            radius: 0.5,
+            modules: 7,
+        }
+    }
+
+    /// Set the ring width in modules (5 for alignment patterns).
+    pub fn with_modules(mut self, modules: usize) -> Self {
+        self.modules = modules.max(3);
+        self
+    }
+
+    /// Set the corner radius as a fraction of half the side (0.0 to 1.0).
+    pub fn with_radius(mut self, radius: f64) -> Self {
+        self.radius = radius.clamp(0.0, 1.0);
+        self
     }
 
     /// Draw the corner square.
     pub fn draw(&self, x: f64, y: f64, size: f64, rotation: f64) -> String {
         match self.square_type {
-            CornerSquareType::Square => self.draw_square(x, y, size, rotation),
+            CornerSquareType::Square | CornerSquareType::Custom => {
+                self.draw_square(x, y, size, rotation)
+            }
             CornerSquareType::Dot => self.draw_dot(x, y, size, rotation),
             CornerSquareType::ExtraRounded => self.draw_extra_rounded(x, y, size, rotation),
+            CornerSquareType::Rounded => self.basic_rounded(x, y, size, rotation, [true; 4]),
+            CornerSquareType::Leaf => {
+                self.basic_rounded(x, y, size, rotation, [true, false, true, false])
+            }
+            CornerSquareType::Octagon => self.basic_octagon(x, y, size, rotation),
+            CornerSquareType::DotsRing => self.basic_dots_ring(x, y, size, rotation),
         }
     }
 
     /// Draw basic dot (ring) shape.
     fn basic_dot(&self, x: f64, y: f64, size: f64, rotation: f64) -> String {
         let transform = rotate_transform(x, y, size, rotation);
-        let dot_size = size / 7.0;
+        let dot_size = size / self.modules as f64;
         let half_size = size / 2.0;
         let inner_radius = half_size - dot_size;
 
@@ -48,7 +79,7 @@ impl QRCornerSquare {
     /// Draw basic square shape with hollow center.
     fn basic_square(&self, x: f64, y: f64, size: f64, rotation: f64) -> String {
         let transform = rotate_transform(x, y, size, rotation);
-        let dot_size = size / 7.0;
+        let dot_size = size / self.modules as f64;
 
         // Outer square + inner square (hollow)
         let d = format!(
@@ -67,40 +98,103 @@ impl QRCornerSquare {
     /// Draw extra-rounded shape.
     fn basic_extra_rounded(&self, x: f64, y: f64, size: f64, rotation: f64) -> String {
         let transform = rotate_transform(x, y, size, rotation);
-        let dot_size = size / 7.0;
-
-        // Outer rounded path
-        let outer = format!(
-            "M {} {} v {} a {} {} 0 0 0 {} {} h {} a {} {} 0 0 0 {} {} v {} a {} {} 0 0 0 {} {} h {} a {} {} 0 0 0 {} {}",
-            x, y + 2.5 * dot_size,
-            2.0 * dot_size,
-            2.5 * dot_size, 2.5 * dot_size, 2.5 * dot_size, 2.5 * dot_size,
-            2.0 * dot_size,
-            2.5 * dot_size, 2.5 * dot_size, 2.5 * dot_size, -2.5 * dot_size,
-            -2.0 * dot_size,
-            2.5 * dot_size, 2.5 * dot_size, -2.5 * dot_size, -2.5 * dot_size,
-            -2.0 * dot_size,
-            2.5 * dot_size, 2.5 * dot_size, -2.5 * dot_size, 2.5 * dot_size
+        let dot_size = size / self.modules as f64;
+        // 2.5 of 7 modules for finder patterns, in proportion otherwise.
+        let outer = size * 2.5 / 7.0;
+        let inner = outer - dot_size;
+
+        let d = format!(
+            "{} {}",
+            rounded_rect_data(x, y, size, size, [outer; 4]),
+            rounded_rect_data(
+                x + dot_size,
+                y + dot_size,
+                size - 2.0 * dot_size,
+                size - 2.0 * dot_size,
+                [inner; 4]
+            )
         );
+        svg_path(&d, Some("evenodd"), transform.as_deref())
+    }
 
-        // Inner rounded path
-        let inner = format!(
-            "M {} {} h {} a {} {} 0 0 1 {} {} v {} a {} {} 0 0 1 {} {} h {} a {} {} 0 0 1 {} {} v {} a {} {} 0 0 1 {} {}",
-            x + 2.5 * dot_size, y + dot_size,
-            2.0 * dot_size,
-            1.5 * dot_size, 1.5 * dot_size, 1.5 * dot_size, 1.5 * dot_size,
-            2.0 * dot_size,
-            1.5 * dot_size, 1.5 * dot_size, -1.5 * dot_size, 1.5 * dot_size,
-            -2.0 * dot_size,
-            1.5 * dot_size, 1.5 * dot_size, -1.5 * dot_size, -1.5 * dot_size,
-            -2.0 * dot_size,
-            1.5 * dot_size, 1.5 * dot_size, 1.5 * dot_size, -1.5 * dot_size
+    /// Draw a ring with the chosen corners rounded; the hole's radius is
+    /// one module smaller so the ring stays one module thick.
+    fn basic_rounded(
+        &self,
+        x: f64,
+        y: f64,
+        size: f64,
+        rotation: f64,
+        corners: [bool; 4],
+    ) -> String {
+        let transform = rotate_transform(x, y, size, rotation);
+        let dot_size = size / self.modules as f64;
+        let outer = self.radius * size / 2.0;
+        let inner = (outer - dot_size).max(0.0);
+        let radii = |r: f64| corners.map(|rounded| if rounded { r } else { 0.0 });
+
+        let d = format!(
+            "{} {}",
+            rounded_rect_data(x, y, size, size, radii(outer)),
+            rounded_rect_data(
+                x + dot_size,
+                y + dot_size,
+                size - 2.0 * dot_size,
+                size - 2.0 * dot_size,
+                radii(inner)
+            )
         );
+        svg_path(&d, Some("evenodd"), transform.as_deref())
+    }
+
+    /// Draw an octagonal ring of even thickness.
+    fn basic_octagon(&self, x: f64, y: f64, size: f64, rotation: f64) -> String {
+        let transform = rotate_transform(x, y, size, rotation);
+        let dot_size = size / self.modules as f64;
+        let inner_size = size - 2.0 * dot_size;
+        let outer_cut = self.radius * size / 2.0;
+        // Moving a 45° edge in by one module shortens its cut by (2 - √2).
+        let inner_cut = (outer_cut - dot_size * (2.0 - SQRT_2)).clamp(0.0, inner_size / 2.0);
 
-        let d = format!("{} {}", outer, inner);
+        let d = format!(
+            "{} {}",
+            polygon_data(&octagon(x, y, size, outer_cut)),
+            polygon_data(&octagon(x + dot_size, y + dot_size, inner_size, inner_cut))
+        );
         svg_path(&d, Some("evenodd"), transform.as_deref())
     }
 
+    /// Draw one dot on each ring module (24 for finder patterns).
+    fn basic_dots_ring(&self, x: f64, y: f64, size: f64, rotation: f64) -> String {
+        let transform = rotate_transform(x, y, size, rotation);
+        let dot_size = size / self.modules as f64;
+        let r = dot_size / 2.0;
+
+        let mut d = String::new();
+        let last = self.modules - 1;
+        for row in 0..self.modules {
+            for col in 0..self.modules {
+                if row != 0 && row != last && col != 0 && col != last {
+                    continue;
+                }
+                let cx = x + (col as f64 + 0.5) * dot_size;
+                let cy = y + (row as f64 + 0.5) * dot_size;
+                d.push_str(&format!(
+                    "M {} {} a {} {} 0 1 0 {} 0 a {} {} 0 1 0 {} 0 Z ",
+                    cx - r,
+                    cy,
+                    r,
+                    r,
+                    2.0 * r,
+                    r,
+                    r,
+                    -2.0 * r
+                ));
+            }
+        }
+        svg_path(d.trim_end(), None, transform.as_deref())
+    }
+
     fn draw_dot(&self, x: f64, y: f64, size: f64, rotation: f64) -> String {
         self.basic_dot(x, y, size, rotation)
     }
@@ -114,6 +208,20 @@ impl QRCornerSquare {
     }
 }
 
+/// Corners of a square with `cut` taken off each corner along both edges.
+fn octagon(x: f64, y: f64, size: f64, cut: f64) -> [(f64, f64); 8] {
+    [
+        (x + cut, y),
+        (x + size - cut, y),
+        (x + size, y + cut),
+        (x + size, y + size - cut),
+        (x + size - cut, y + size),
+        (x + cut, y + size),
+        (x, y + size - cut),
+        (x, y + cut),
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -140,4 +248,82 @@ mod tests {
         let svg = drawer.draw(0.0, 0.0, 70.0, 0.0);
         assert!(svg.contains("path"));
     }
+
+    #[test]
+    fn test_draw_tunable_shapes() {
+        for square_type in [
+            CornerSquareType::Rounded,
+            CornerSquareType::Leaf,
+            CornerSquareType::Octagon,
+        ] {
+            let sharp = QRCornerSquare::new(square_type)
+                .with_radius(0.0)
+                .draw(0.0, 0.0, 70.0, 0.0);
+            let soft = QRCornerSquare::new(square_type)
+                .with_radius(1.0)
+                .draw(0.0, 0.0, 70.0, 0.0);
+            assert!(sharp.contains("evenodd"));
+            assert_ne!(sharp, soft);
+        }
+    }
+
+    #[test]
+    fn test_draw_dots_ring() {
+        let svg = QRCornerSquare::new(CornerSquareType::DotsRing).draw(0.0, 0.0, 70.0, 0.0);
+        assert_eq!(svg.matches('M').count(), 24);
+        // The first dot sits on the top-left module.
+        assert!(svg.contains("M 0 5 a 5 5"));
+    }
+
+    #[test]
+    fn test_alignment_sized_ring() {
+        let ring = QRCornerSquare::new(CornerSquareType::DotsRing).with_modules(5);
+        assert_eq!(ring.draw(0.0, 0.0, 50.0, 0.0).matches('M').count(), 16);
+        let square = QRCornerSquare::new(CornerSquareType::Square).with_modules(5);
+        // The hole starts one module (10) in.
+        assert!(square.draw(0.0, 0.0, 50.0, 0.0).contains("M 10 10 h 30"));
+    }
+
+    #[test]
+    fn test_shapes_keep_finder_profile() {
+        use crate::config::{CornersDotOptions, CornersSquareOptions};
+        use crate::types::CornerDotType;
+        use crate::QRCodeStyling;
+
+        // Radii are capped at what the detector reads, so the largest one
+        // is the hardest case.
+        let squares = CornerSquareType::all();
+        let dots = CornerDotType::all();
+        for i in 0..squares.len().max(dots.len()) {
+            let (square, dot) = (squares[i % squares.len()], dots[i % dots.len()]);
+            let qr = QRCodeStyling::builder()
+                .data("https://example.com/corner-shapes")
+                .corners_square_options(CornersSquareOptions::new(square).with_radius(1.0))
+                .corners_dot_options(CornersDotOptions::new(dot).with_radius(1.0))
+                .build()
+                .unwrap();
+            let report = qr.verify().unwrap();
+            assert!(
+                report.decodes_with_known_geometry(),
+                "{:?} {:?}: {:?}",
+                square,
+                dot,
+                report.error
+            );
+            // Round outer corners keep the detector from fitting the grid.
+            assert_eq!(report.is_ok(), !square.is_round(), "{:?} {:?}", square, dot);
+            assert_eq!(
+                report.round_finders.is_empty(),
+                !square.is_round(),
+                "{:?}",
+                square
+            );
+            assert!(
+                report.missing_finders().is_empty(),
+                "{:?} {:?}",
+                square,
+                dot
+            );
+        }
+    }
 }
@@ -2,18 +2,74 @@
 
 use std::f64::consts::PI;
 
-use crate::figures::traits::{rotate_transform, svg_circle, svg_path, svg_rect};
+use super::DotJitter;
+use crate::figures::traits::{
+    polygon_data, prepend_transform, rotate_transform, svg_circle, svg_path, svg_rect,
+    CustomDotDrawer, DotContext,
+};
 use crate::types::DotType;
 
+/// Dark state of all eight surrounding modules.
+#[derive(Debug, Clone, Copy, Default)]
+struct Neighbors8 {
+    left: bool,
+    right: bool,
+    top: bool,
+    bottom: bool,
+    top_left: bool,
+    top_right: bool,
+    bottom_left: bool,
+    bottom_right: bool,
+}
+
 /// QR code dot drawer.
 pub struct QRDot {
     dot_type: DotType,
+    custom_drawer: Option<CustomDotDrawer>,
+    jitter: DotJitter,
 }
 
 impl QRDot {
     /// Create a new dot drawer with the specified type.
     pub fn new(dot_type: DotType) -> Self {
-        Self { dot_type }
+        Self {
+            dot_type,
+            custom_drawer: None,
+            jitter: DotJitter::default(),
+        }
+    }
+
+    /// Set the drawer used for [`DotType::Custom`].
+    pub fn with_custom_drawer(mut self, drawer: Option<CustomDotDrawer>) -> Self {
+        self.custom_drawer = drawer;
+        self
+    }
+
+    /// Set the per-module scale, offset and rotation used by [`draw_module`](Self::draw_module).
+    pub fn with_jitter(mut self, jitter: DotJitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Draw the module at `row`/`col` in the cell at `x`/`y`, applying the jitter.
+    pub fn draw_module<F>(
+        &self,
+        row: usize,
+        col: usize,
+        x: f64,
+        y: f64,
+        size: f64,
+        get_neighbor: Option<F>,
+    ) -> String
+    where
+        F: Fn(i32, i32) -> bool,
+    {
+        let placement = self.jitter.place(row, col, x, y, size);
+        let svg = self.draw(placement.x, placement.y, placement.size, get_neighbor);
+        match rotate_transform(placement.x, placement.y, placement.size, placement.rotation) {
+            Some(transform) => prepend_transform(&svg, &transform),
+            None => svg,
+        }
     }
 
     /// Draw a basic dot (circle).
@@ -76,7 +132,149 @@ impl QRDot {
         svg_path(&d, None, transform.as_deref())
     }
 
+    /// Draw a closed polygon through `points`.
+    fn basic_polygon(&self, points: &[(f64, f64)]) -> String {
+        svg_path(&polygon_data(points), None, None)
+    }
+
+    /// Draw a diamond touching the middle of each module edge.
+    fn basic_diamond(&self, x: f64, y: f64, size: f64) -> String {
+        let half = size / 2.0;
+        self.basic_polygon(&[
+            (x + half, y),
+            (x + size, y + half),
+            (x + half, y + size),
+            (x, y + half),
+        ])
+    }
+
+    /// Draw a five-pointed star pointing up.
+    fn basic_star(&self, x: f64, y: f64, size: f64) -> String {
+        let (cx, cy) = (x + size / 2.0, y + size / 2.0);
+        // A fat inner radius keeps the module center solidly dark and the
+        // star over half the cell, the least a detector accepts as the
+        // center of an alignment pattern.
+        let (outer, inner) = (size / 2.0, size * 0.36);
+        let points: Vec<(f64, f64)> = (0..10)
+            .map(|i| {
+                let angle = -PI / 2.0 + i as f64 * PI / 5.0;
+                let r = if i % 2 == 0 { outer } else { inner };
+                (cx + r * angle.cos(), cy + r * angle.sin())
+            })
+            .collect();
+        self.basic_polygon(&points)
+    }
+
+    /// Draw a heart with its point at the bottom, filling over half the cell.
+    fn basic_heart(&self, x: f64, y: f64, size: f64) -> String {
+        let r = size * 0.26;
+        let d = format!(
+            "M {} {} L {} {} A {} {} 0 0 1 {} {} A {} {} 0 0 1 {} {} Z",
+            x + size * 0.5,
+            y + size,
+            x + size * 0.02,
+            y + size * 0.42,
+            r,
+            r,
+            x + size * 0.5,
+            y + size * 0.22,
+            r,
+            r,
+            x + size * 0.98,
+            y + size * 0.42
+        );
+        svg_path(&d, None, None)
+    }
+
+    /// Draw a pointy-top hexagon.
+    fn basic_hexagon(&self, x: f64, y: f64, size: f64) -> String {
+        let (cx, cy) = (x + size / 2.0, y + size / 2.0);
+        let r = size / 2.0;
+        let points: Vec<(f64, f64)> = (0..6)
+            .map(|i| {
+                let angle = -PI / 2.0 + i as f64 * PI / 3.0;
+                (cx + r * angle.cos(), cy + r * angle.sin())
+            })
+            .collect();
+        self.basic_polygon(&points)
+    }
+
+    /// Draw a plus sign with arms 40% of the module wide.
+    fn basic_cross(&self, x: f64, y: f64, size: f64) -> String {
+        let a = size * 0.3;
+        let b = size * 0.7;
+        self.basic_polygon(&[
+            (x + a, y),
+            (x + b, y),
+            (x + b, y + a),
+            (x + size, y + a),
+            (x + size, y + b),
+            (x + b, y + b),
+            (x + b, y + size),
+            (x + a, y + size),
+            (x + a, y + b),
+            (x, y + b),
+            (x, y + a),
+            (x + a, y + a),
+        ])
+    }
+
+    /// Draw one module of a bar running along an axis.
+    ///
+    /// `before`/`after` say whether the run continues on either side; open
+    /// ends get a round cap so a run reads as one pill-shaped stroke.
+    fn basic_bar(
+        &self,
+        x: f64,
+        y: f64,
+        size: f64,
+        before: bool,
+        after: bool,
+        vertical: bool,
+    ) -> String {
+        let width = size * 0.8;
+        let r = width / 2.0;
+        let inset = (size - width) / 2.0;
+
+        // Build in bar-local coordinates: `u` along the bar, `v` across it.
+        let point = |u: f64, v: f64| -> (f64, f64) {
+            if vertical {
+                (x + v, y + u)
+            } else {
+                (x + u, y + v)
+            }
+        };
+        let mut d = String::new();
+        let sweep = if vertical { 0 } else { 1 };
+        // Caps end at the cell edge, so a lone module is a full-length pill.
+        let (sx, sy) = point(if before { 0.0 } else { r }, inset);
+        d.push_str(&format!("M {} {} ", sx, sy));
+
+        // Along the first side to the far end, then across it.
+        let (ex, ey) = point(if after { size } else { size - r }, inset);
+        d.push_str(&format!("L {} {} ", ex, ey));
+        let (fx, fy) = point(if after { size } else { size - r }, inset + width);
+        if after {
+            d.push_str(&format!("L {} {} ", fx, fy));
+        } else {
+            d.push_str(&format!("A {} {} 0 0 {} {} {} ", r, r, sweep, fx, fy));
+        }
+
+        // Back along the second side, then across the near end.
+        let (bx, by) = point(if before { 0.0 } else { r }, inset + width);
+        d.push_str(&format!("L {} {} ", bx, by));
+        if !before {
+            d.push_str(&format!("A {} {} 0 0 {} {} {} ", r, r, sweep, sx, sy));
+        }
+        d.push('Z');
+        svg_path(&d, None, None)
+    }
+
     /// Draw the dot and return SVG element string.
+    ///
+    /// # Panics
+    ///
+    /// Panics for [`DotType::Custom`] without a custom drawer.
     pub fn draw<F>(&self, x: f64, y: f64, size: f64, get_neighbor: Option<F>) -> String
     where
         F: Fn(i32, i32) -> bool,
@@ -88,6 +286,30 @@ impl QRDot {
             DotType::ExtraRounded => self.draw_extra_rounded(x, y, size, get_neighbor),
             DotType::Classy => self.draw_classy(x, y, size, get_neighbor),
             DotType::ClassyRounded => self.draw_classy_rounded(x, y, size, get_neighbor),
+            DotType::Diamond => self.basic_diamond(x, y, size),
+            DotType::Star => self.basic_star(x, y, size),
+            DotType::Heart => self.basic_heart(x, y, size),
+            DotType::Hexagon => self.basic_hexagon(x, y, size),
+            DotType::Cross => self.basic_cross(x, y, size),
+            DotType::VerticalBars => {
+                let (_, _, top, bottom) = self.get_neighbors(&get_neighbor);
+                self.basic_bar(x, y, size, top == 1, bottom == 1, true)
+            }
+            DotType::HorizontalBars => {
+                let (left, right, _, _) = self.get_neighbors(&get_neighbor);
+                self.basic_bar(x, y, size, left == 1, right == 1, false)
+            }
+            DotType::Liquid => self.draw_liquid(x, y, size, get_neighbor),
+            DotType::Custom => match &self.custom_drawer {
+                Some(drawer) => {
+                    let neighbor = get_neighbor
+                        .as_ref()
+                        .map(|f| f as &dyn Fn(i32, i32) -> bool);
+                    let d = drawer.path(&DotContext::new(x, y, size, neighbor));
+                    svg_path(&d, None, None)
+                }
+                None => panic!("DotType::Custom needs a custom drawer"),
+            },
         }
     }
 
@@ -175,6 +397,60 @@ impl QRDot {
         self.basic_side_rounded(x, y, size, rotation)
     }
 
+    /// Draw a single liquid module.
+    ///
+    /// The renderer traces whole regions with [`liquid_path`](super::liquid_path);
+    /// this per-module form rounds a corner only when no orthogonal or
+    /// diagonal neighbor touches it, which matches the traced outline on
+    /// the module's own area.
+    fn draw_liquid<F>(&self, x: f64, y: f64, size: f64, get_neighbor: Option<F>) -> String
+    where
+        F: Fn(i32, i32) -> bool,
+    {
+        let n = self.get_neighbors8(&get_neighbor);
+        let half = size / 2.0;
+        // Corners clockwise from top-left: (orthogonal, orthogonal, diagonal).
+        let round = [
+            !(n.left || n.top || n.top_left),
+            !(n.top || n.right || n.top_right),
+            !(n.right || n.bottom || n.bottom_right),
+            !(n.bottom || n.left || n.bottom_left),
+        ];
+        let corner = |rounded: bool, from: (f64, f64), to: (f64, f64), at: (f64, f64)| {
+            if rounded {
+                format!(
+                    "L {} {} A {} {} 0 0 1 {} {} ",
+                    from.0, from.1, half, half, to.0, to.1
+                )
+            } else {
+                format!("L {} {} ", at.0, at.1)
+            }
+        };
+
+        let mut d = format!("M {} {} ", x + half, y);
+        d.push_str(&corner(
+            round[1],
+            (x + half, y),
+            (x + size, y + half),
+            (x + size, y),
+        ));
+        d.push_str(&corner(
+            round[2],
+            (x + size, y + half),
+            (x + half, y + size),
+            (x + size, y + size),
+        ));
+        d.push_str(&corner(
+            round[3],
+            (x + half, y + size),
+            (x, y + half),
+            (x, y + size),
+        ));
+        d.push_str(&corner(round[0], (x, y + half), (x + half, y), (x, y)));
+        d.push('Z');
+        svg_path(&d, None, None)
+    }
+
     /// Draw classy type based on neighbors.
     fn draw_classy<F>(&self, x: f64, y: f64, size: f64, get_neighbor: Option<F>) -> String
     where
@@ -222,6 +498,25 @@ impl QRDot {
     }
 
     /// Get neighbor states.
+    fn get_neighbors8<F>(&self, get_neighbor: &Option<F>) -> Neighbors8
+    where
+        F: Fn(i32, i32) -> bool,
+    {
+        match get_neighbor {
+            Some(f) => Neighbors8 {
+                left: f(-1, 0),
+                right: f(1, 0),
+                top: f(0, -1),
+                bottom: f(0, 1),
+                top_left: f(-1, -1),
+                top_right: f(1, -1),
+                bottom_left: f(-1, 1),
+                bottom_right: f(1, 1),
+            },
+            None => Neighbors8::default(),
+        }
+    }
+
     fn get_neighbors<F>(&self, get_neighbor: &Option<F>) -> (u8, u8, u8, u8)
     where
         F: Fn(i32, i32) -> bool,
@@ -266,6 +561,200 @@ mod tests {
         assert!(svg.contains("circle"));
     }
 
+    #[test]
+    fn test_draw_bars() {
+        let drawer = QRDot::new(DotType::VerticalBars);
+        // Isolated module: both ends capped.
+        let svg = drawer.draw(0.0, 0.0, 10.0, None::<fn(i32, i32) -> bool>);
+        assert_eq!(svg.matches(" A ").count(), 2);
+        // Middle of a vertical run: a plain rectangle spanning the module.
+        let svg = drawer.draw(0.0, 0.0, 10.0, Some(|_x: i32, y: i32| y != 0));
+        assert!(!svg.contains(" A "));
+        assert!(svg.contains("M 1 0 L 1 10"));
+        // Horizontal neighbors do not join vertical bars.
+        let svg = drawer.draw(0.0, 0.0, 10.0, Some(|x: i32, _y: i32| x != 0));
+        assert_eq!(svg.matches(" A ").count(), 2);
+
+        let drawer = QRDot::new(DotType::HorizontalBars);
+        let svg = drawer.draw(0.0, 0.0, 10.0, Some(|x: i32, _y: i32| x == 1));
+        assert_eq!(svg.matches(" A ").count(), 1);
+    }
+
+    #[test]
+    fn test_draw_isolated_shapes() {
+        let isolated = [
+            DotType::Diamond,
+            DotType::Star,
+            DotType::Heart,
+            DotType::Hexagon,
+            DotType::Cross,
+        ];
+        for dot_type in isolated {
+            // Neighbors never change isolated shapes.
+            let alone = QRDot::new(dot_type).draw(0.0, 0.0, 10.0, None::<fn(i32, i32) -> bool>);
+            let crowded = QRDot::new(dot_type).draw(0.0, 0.0, 10.0, Some(|_x: i32, _y: i32| true));
+            assert_eq!(alone, crowded);
+            assert!(alone.starts_with("<path d=\"M "));
+        }
+    }
+
+    #[test]
+    fn test_shapes_decode() {
+        use crate::config::{Color, ColorStop, DotsOptions, Gradient};
+        use crate::types::OutputFormat;
+        use crate::QRCodeStyling;
+
+        let gradient = Gradient::linear(vec![
+            ColorStop::new(0.0, Color::rgb(20, 20, 90)),
+            ColorStop::new(1.0, Color::rgb(90, 20, 40)),
+        ]);
+        let types = [
+            DotType::Diamond,
+            DotType::Star,
+            DotType::Heart,
+            DotType::Hexagon,
+            DotType::Cross,
+            DotType::VerticalBars,
+            DotType::HorizontalBars,
+            DotType::Liquid,
+        ];
+        for dot_type in types {
+            let qr = QRCodeStyling::builder()
+                .data("https://example.com/shapes")
+                .dots_options(DotsOptions::new(dot_type).with_gradient(gradient.clone()))
+                .build()
+                .unwrap();
+            let report = qr.verify().unwrap();
+            assert!(report.is_ok(), "{:?}: {:?}", dot_type, report.error);
+            assert!(!qr.render(OutputFormat::Pdf).unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_draw_liquid_module() {
+        let drawer = QRDot::new(DotType::Liquid);
+        let alone = drawer.draw(0.0, 0.0, 10.0, None::<fn(i32, i32) -> bool>);
+        assert_eq!(alone.matches(" A ").count(), 4);
+
+        // A diagonal neighbor squares off the corner it touches.
+        let diagonal = drawer.draw(0.0, 0.0, 10.0, Some(|x: i32, y: i32| (x, y) == (1, 1)));
+        assert_eq!(diagonal.matches(" A ").count(), 3);
+        assert!(diagonal.contains("L 10 10"));
+    }
+
+    #[test]
+    fn test_draw_module_jitter() {
+        let none = None::<fn(i32, i32) -> bool>;
+        let plain = QRDot::new(DotType::Square);
+        assert_eq!(
+            plain.draw_module(1, 2, 0.0, 0.0, 10.0, none),
+            plain.draw(0.0, 0.0, 10.0, none)
+        );
+
+        let scaled = QRDot::new(DotType::Square).with_jitter(DotJitter {
+            scale: 0.8,
+            ..Default::default()
+        });
+        assert_eq!(
+            scaled.draw_module(1, 2, 0.0, 0.0, 10.0, none),
+            plain.draw(1.0, 1.0, 8.0, none)
+        );
+
+        let rotated = QRDot::new(DotType::Rounded).with_jitter(DotJitter {
+            rotation_jitter: 0.5,
+            seed: 3,
+            ..Default::default()
+        });
+        let svg = rotated.draw_module(1, 2, 0.0, 0.0, 10.0, none);
+        assert_eq!(svg.matches("transform=").count(), 1);
+        assert!(svg.contains(r#"transform="rotate("#) && svg.ends_with("/>"));
+
+        // An existing transform is kept after the jitter rotation.
+        let svg = rotated.draw_module(1, 2, 0.0, 0.0, 10.0, Some(|x: i32, _y: i32| x == 1));
+        assert_eq!(svg.matches("transform=").count(), 1);
+        assert_eq!(svg.matches("rotate(").count(), 2);
+    }
+
+    #[test]
+    fn test_draw_custom() {
+        let drawer = QRDot::new(DotType::Custom).with_custom_drawer(Some(CustomDotDrawer::new(
+            |ctx: &DotContext| {
+                let inset = if ctx.neighbor(1, 0) { 0.0 } else { 1.0 };
+                format!(
+                    "M {} {} h {} v {} z",
+                    ctx.x,
+                    ctx.y,
+                    ctx.size - inset,
+                    ctx.size
+                )
+            },
+        )));
+        let svg = drawer.draw(0.0, 0.0, 10.0, Some(|x: i32, _y: i32| x == 1));
+        assert_eq!(svg, r#"<path d="M 0 0 h 10 v 10 z"/>"#);
+        let svg = drawer.draw(0.0, 0.0, 10.0, None::<fn(i32, i32) -> bool>);
+        assert!(svg.contains("h 9 v 10"));
+
+        // Path data cannot break out of the attribute.
+        let drawer = QRDot::new(DotType::Custom).with_custom_drawer(Some(CustomDotDrawer::new(
+            |_: &DotContext| r#"M 0 0"/><script/><path d=""#.to_string(),
+        )));
+        let svg = drawer.draw(0.0, 0.0, 10.0, None::<fn(i32, i32) -> bool>);
+        assert_eq!(
+            svg,
+            r#"<path d="M 0 0&quot;/&gt;&lt;script/&gt;&lt;path d=&quot;"/>"#
+        );
+    }
+
+    #[test]
+    fn test_custom_drawer_decode() {
+        use crate::config::DotsOptions;
+        use crate::error::QRError;
+        use crate::QRCodeStyling;
+
+        let inset_square = |ctx: &DotContext| {
+            let inset = ctx.size * 0.1;
+            let side = ctx.size - inset * 2.0;
+            format!(
+                "M {} {} h {} v {} h {} z",
+                ctx.x + inset,
+                ctx.y + inset,
+                side,
+                side,
+                -side
+            )
+        };
+        let qr = QRCodeStyling::builder()
+            .data("https://example.com")
+            .dots_options(DotsOptions::default().with_custom_drawer(inset_square))
+            .build()
+            .unwrap();
+
+        assert_eq!(qr.options().dots_options.dot_type, DotType::Custom);
+        assert!(qr.render_svg().unwrap().contains("<path d=\"M "));
+        // Raster output goes through the same SVG, so it decodes too.
+        let report = qr.verify().unwrap();
+        assert!(report.is_ok(), "{:?}", report.error);
+
+        // The drawer is not serialized, so a deserialized copy must fail
+        // rather than fall back to squares.
+        let mut options = qr.options().clone();
+        options.dots_options.custom_drawer = None;
+        assert!(matches!(
+            QRCodeStyling::new(options),
+            Err(QRError::InvalidOptions(_))
+        ));
+        let mut qr = qr;
+        qr.options_mut().dots_options.custom_drawer = None;
+        assert!(matches!(qr.render_svg(), Err(QRError::InvalidOptions(_))));
+        assert!(matches!(qr.regenerate(), Err(QRError::InvalidOptions(_))));
+    }
+
+    #[test]
+    #[should_panic(expected = "custom drawer")]
+    fn test_draw_custom_without_drawer() {
+        QRDot::new(DotType::Custom).draw(0.0, 0.0, 10.0, None::<fn(i32, i32) -> bool>);
+    }
+
     #[test]
     fn test_draw_rounded_with_neighbors() {
         let drawer = QRDot::new(DotType::Rounded);
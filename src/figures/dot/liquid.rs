@@ -0,0 +1,194 @@
+//! Merged outline paths for the liquid dot style.
+//!
+//! Dark modules are traced as the boundary of their union. Every corner of
+//! the boundary is replaced by a quarter circle of half a module: convex
+//! corners become rounded, concave ones become fillets. Modules touching
+//! only diagonally are joined, so the bridge between them gets fillets too.
+
+use std::collections::{HashMap, HashSet};
+
+type Point = (i32, i32);
+type Direction = (i32, i32);
+
+/// Build one `<path>` element covering every dark module.
+///
+/// `is_dark(row, col)` reports whether a module is drawn; `x`/`y` are the
+/// pixel position of the grid's top-left corner.
+pub fn liquid_path<F>(count: usize, x: f64, y: f64, size: f64, is_dark: F) -> String
+where
+    F: Fn(usize, usize) -> bool,
+{
+    region_path(count, x, y, size, size / 2.0, is_dark)
+}
+
+/// Like [`liquid_path`], with corners rounded by `radius` pixels instead of
+/// half a module; a radius of 0 keeps the square corners of the modules.
+pub fn region_path<F>(count: usize, x: f64, y: f64, size: f64, radius: f64, is_dark: F) -> String
+where
+    F: Fn(usize, usize) -> bool,
+{
+    let dark = |row: i32, col: i32| -> bool {
+        row >= 0
+            && col >= 0
+            && (row as usize) < count
+            && (col as usize) < count
+            && is_dark(row as usize, col as usize)
+    };
+
+    let r = radius.clamp(0.0, size / 2.0);
+    let px = |(gx, gy): Point| (x + gx as f64 * size, y + gy as f64 * size);
+
+    let mut d = String::new();
+    for outline in trace_outlines(count, dark) {
+        let corners = corners(&outline);
+        for (i, &(point, din, dout)) in corners.iter().enumerate() {
+            let (cx, cy) = px(point);
+            let command = if i == 0 { "M" } else { "L" };
+            if r == 0.0 {
+                d.push_str(&format!("{} {} {} ", command, cx, cy));
+                continue;
+            }
+            let start = (cx - din.0 as f64 * r, cy - din.1 as f64 * r);
+            let end = (cx + dout.0 as f64 * r, cy + dout.1 as f64 * r);
+            // A clockwise turn on screen sweeps in the positive direction.
+            let sweep = if din.0 * dout.1 - din.1 * dout.0 > 0 {
+                1
+            } else {
+                0
+            };
+            d.push_str(&format!(
+                "{} {} {} A {} {} 0 0 {} {} {} ",
+                command, start.0, start.1, r, r, sweep, end.0, end.1
+            ));
+        }
+        d.push_str("Z ");
+    }
+
+    format!(r#"<path d="{}"/>"#, d.trim_end())
+}
+
+/// Closed boundary loops of the dark region, as (vertex, outgoing direction)
+/// pairs with the region on the right-hand side.
+fn trace_outlines<F>(count: usize, dark: F) -> Vec<Vec<(Point, Direction)>>
+where
+    F: Fn(i32, i32) -> bool,
+{
+    let mut edges: Vec<(Point, Direction)> = Vec::new();
+    for row in 0..count as i32 {
+        for col in 0..count as i32 {
+            if !dark(row, col) {
+                continue;
+            }
+            if !dark(row - 1, col) {
+                edges.push(((col, row), (1, 0)));
+            }
+            if !dark(row, col + 1) {
+                edges.push(((col + 1, row), (0, 1)));
+            }
+            if !dark(row + 1, col) {
+                edges.push(((col + 1, row + 1), (-1, 0)));
+            }
+            if !dark(row, col - 1) {
+                edges.push(((col, row + 1), (0, -1)));
+            }
+        }
+    }
+
+    let mut outgoing: HashMap<Point, Vec<Direction>> = HashMap::new();
+    for &(start, direction) in &edges {
+        outgoing.entry(start).or_default().push(direction);
+    }
+
+    let mut visited: HashSet<(Point, Direction)> = HashSet::new();
+    let mut outlines = Vec::new();
+    for &edge in &edges {
+        if visited.contains(&edge) {
+            continue;
+        }
+        let mut outline = Vec::new();
+        let (mut point, mut direction) = edge;
+        loop {
+            visited.insert((point, direction));
+            outline.push((point, direction));
+            point = (point.0 + direction.0, point.1 + direction.1);
+
+            // Where two modules meet only at a corner there are two ways
+            // on; turning left keeps them in one outline.
+            let next = outgoing[&point]
+                .iter()
+                .copied()
+                .filter(|&d| !visited.contains(&(point, d)))
+                .min_by_key(|&d| direction.0 * d.1 - direction.1 * d.0);
+            match next {
+                Some(next) => direction = next,
+                None => break,
+            }
+        }
+        outlines.push(outline);
+    }
+    outlines
+}
+
+/// Vertices where the outline changes direction, with the incoming and
+/// outgoing directions.
+fn corners(outline: &[(Point, Direction)]) -> Vec<(Point, Direction, Direction)> {
+    let n = outline.len();
+    (0..n)
+        .filter_map(|i| {
+            let (point, dout) = outline[i];
+            let (_, din) = outline[(i + n - 1) % n];
+            (din != dout).then_some((point, din, dout))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid<'a>(rows: &'a [&'a str]) -> impl Fn(usize, usize) -> bool + 'a {
+        move |row, col| rows[row].as_bytes()[col] == b'#'
+    }
+
+    fn path(rows: &[&str]) -> String {
+        liquid_path(rows.len(), 0.0, 0.0, 10.0, grid(rows))
+    }
+
+    #[test]
+    fn test_single_module_is_circle() {
+        let d = path(&["...", ".#.", "..."]);
+        assert_eq!(d.matches('M').count(), 1);
+        assert_eq!(d.matches(" A 5 5 0 0 1 ").count(), 4);
+    }
+
+    #[test]
+    fn test_inner_corner_gets_fillet() {
+        // An L of three modules: five rounded corners and one fillet.
+        let d = path(&["##", "#."]);
+        assert_eq!(d.matches('M').count(), 1);
+        assert_eq!(d.matches(" 0 0 1 ").count(), 5);
+        assert_eq!(d.matches(" 0 0 0 ").count(), 1);
+    }
+
+    #[test]
+    fn test_diagonal_modules_are_bridged() {
+        let d = path(&["#.", ".#"]);
+        assert_eq!(d.matches('M').count(), 1);
+        assert_eq!(d.matches(" 0 0 0 ").count(), 2);
+    }
+
+    #[test]
+    fn test_square_corners_without_radius() {
+        let d = region_path(2, 0.0, 0.0, 10.0, 0.0, grid(&["##", "#."]));
+        assert_eq!(
+            d,
+            r#"<path d="M 0 0 L 20 0 L 20 10 L 10 10 L 10 20 L 0 20 Z"/>"#
+        );
+    }
+
+    #[test]
+    fn test_hole_is_separate_outline() {
+        let d = path(&["###", "#.#", "###"]);
+        assert_eq!(d.matches('M').count(), 2);
+    }
+}
@@ -1,5 +1,9 @@
 //! QR code dot drawing.
 
 mod drawer;
+mod jitter;
+mod liquid;
 
 pub use drawer::QRDot;
+pub use jitter::{shape_coverage, DotJitter, DotPlacement, MIN_MODULE_AREA, MIN_MODULE_SCALE};
+pub use liquid::{liquid_path, region_path};
@@ -1,6 +1,7 @@
 //! Traits for figure drawing.
 
 use std::f64::consts::PI;
+use std::sync::Arc;
 
 /// Trait for drawing QR code figures.
 pub trait FigureDrawer {
@@ -10,6 +11,83 @@ pub trait FigureDrawer {
         F: Fn(i32, i32) -> bool;
 }
 
+/// Position, size and neighbors of the module handed to a [`DotDrawer`].
+pub struct DotContext<'a> {
+    /// Left edge of the module in pixels.
+    pub x: f64,
+    /// Top edge of the module in pixels.
+    pub y: f64,
+    /// Module size in pixels.
+    pub size: f64,
+    neighbor: Option<&'a dyn Fn(i32, i32) -> bool>,
+}
+
+impl<'a> DotContext<'a> {
+    /// Create a context; `neighbor` answers whether the module at a
+    /// (column, row) offset is drawn dark.
+    pub fn new(x: f64, y: f64, size: f64, neighbor: Option<&'a dyn Fn(i32, i32) -> bool>) -> Self {
+        Self {
+            x,
+            y,
+            size,
+            neighbor,
+        }
+    }
+
+    /// Returns true if the module at the (column, row) offset is drawn dark.
+    pub fn neighbor(&self, x_offset: i32, y_offset: i32) -> bool {
+        self.neighbor.is_some_and(|f| f(x_offset, y_offset))
+    }
+}
+
+/// User-supplied dot shape used by [`DotType::Custom`](crate::types::DotType::Custom).
+///
+/// Closures taking a [`DotContext`] and returning path data implement it.
+pub trait DotDrawer: Send + Sync {
+    /// SVG path data (the `d` attribute) for one dark module.
+    fn path(&self, context: &DotContext) -> String;
+}
+
+impl<F> DotDrawer for F
+where
+    F: Fn(&DotContext) -> String + Send + Sync,
+{
+    fn path(&self, context: &DotContext) -> String {
+        self(context)
+    }
+}
+
+/// Shared handle to a [`DotDrawer`], stored in
+/// [`DotsOptions`](crate::config::DotsOptions).
+///
+/// Two handles are equal when they point to the same drawer.
+#[derive(Clone)]
+pub struct CustomDotDrawer(Arc<dyn DotDrawer>);
+
+impl CustomDotDrawer {
+    /// Wrap a drawer.
+    pub fn new(drawer: impl DotDrawer + 'static) -> Self {
+        Self(Arc::new(drawer))
+    }
+
+    /// SVG path data for one dark module.
+    pub fn path(&self, context: &DotContext) -> String {
+        self.0.path(context)
+    }
+}
+
+impl std::fmt::Debug for CustomDotDrawer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CustomDotDrawer")
+    }
+}
+
+impl PartialEq for CustomDotDrawer {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
 /// Legacy type alias for compatibility.
 pub type NeighborFn = dyn Fn(i32, i32) -> bool;
 
@@ -24,6 +102,51 @@ pub fn rotate_transform(x: f64, y: f64, size: f64, rotation: f64) -> Option<Stri
     Some(format!("rotate({},{},{})", degrees, cx, cy))
 }
 
+/// Prepend `transform` to an element's transform attribute, adding one if
+/// the element has none.
+pub fn prepend_transform(element: &str, transform: &str) -> String {
+    const ATTRIBUTE: &str = "transform=\"";
+    if let Some(i) = element.find(ATTRIBUTE) {
+        let at = i + ATTRIBUTE.len();
+        return format!("{}{} {}", &element[..at], transform, &element[at..]);
+    }
+    match element.rfind("/>") {
+        Some(i) => format!(
+            r#"{} transform="{}"{}"#,
+            element[..i].trim_end(),
+            transform,
+            &element[i..]
+        ),
+        None => element.to_string(),
+    }
+}
+
+/// Path data for a rectangle with per-corner radii, given clockwise from
+/// the top-left corner.
+pub fn rounded_rect_data(x: f64, y: f64, width: f64, height: f64, radii: [f64; 4]) -> String {
+    let limit = width.min(height) / 2.0;
+    let [tl, tr, br, bl] = radii.map(|r| r.clamp(0.0, limit));
+    format!(
+        "M {} {} H {} A {} {} 0 0 1 {} {} V {} A {} {} 0 0 1 {} {} H {} A {} {} 0 0 1 {} {} V {} A {} {} 0 0 1 {} {} Z",
+        x + tl, y,
+        x + width - tr, tr, tr, x + width, y + tr,
+        y + height - br, br, br, x + width - br, y + height,
+        x + bl, bl, bl, x, y + height - bl,
+        y + tl, tl, tl, x + tl, y
+    )
+}
+
+/// Path data for a closed polygon.
+pub fn polygon_data(points: &[(f64, f64)]) -> String {
+    let mut d = String::new();
+    for (i, (px, py)) in points.iter().enumerate() {
+        let command = if i == 0 { "M" } else { "L" };
+        d.push_str(&format!("{} {} {} ", command, px, py));
+    }
+    d.push('Z');
+    d
+}
+
 /// Helper to create SVG circle element.
 pub fn svg_circle(cx: f64, cy: f64, r: f64, transform: Option<&str>) -> String {
     match transform {
@@ -49,9 +172,26 @@ pub fn svg_rect(x: f64, y: f64, width: f64, height: f64, transform: Option<&str>
     }
 }
 
+/// Escape text for use inside a double-quoted XML attribute.
+pub fn escape_attribute(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 /// Helper to create SVG path element.
+///
+/// `d` may come from user drawers or artwork, so it is escaped.
 pub fn svg_path(d: &str, clip_rule: Option<&str>, transform: Option<&str>) -> String {
-    let mut attrs = format!(r#"d="{}""#, d);
+    let mut attrs = format!(r#"d="{}""#, escape_attribute(d));
     if let Some(rule) = clip_rule {
         attrs.push_str(&format!(r#" clip-rule="{}""#, rule));
     }
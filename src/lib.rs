@@ -5,13 +5,34 @@
 //!
 //! ## Features
 //!
-//! - 6 dot styles (square, dots, rounded, classy, classy-rounded, extra-rounded)
-//! - 3 corner square styles (square, dot, extra-rounded)
-//! - 2 corner dot styles (dot, square)
-//! - Linear and radial gradient support
-//! - Logo/image embedding with automatic sizing
+//! - 14 dot styles (square, dots, rounded, classy, classy-rounded, extra-rounded,
+//!   diamond, star, heart, hexagon, cross, vertical and horizontal bars,
+//!   liquid)
+//! - Module scale and seeded size, position and rotation jitter
+//! - Per-module colors from palettes or a custom function
+//! - Separate styles for timing, alignment and format modules
+//! - Alignment patterns drawn whole with their own ring and center shapes
+//! - 7 corner square styles (square, dot, extra-rounded, rounded, leaf,
+//!   octagon, dots-ring) with tunable radii
+//! - 7 corner dot styles (dot, square, rounded, leaf, diamond, star, heart)
+//! - Custom finder artwork from SVG path data or images
+//! - Per-corner finder pattern overrides and orientation modes
+//! - Linear, radial and conic gradients with per-stop opacity, explicit
+//!   center, radius, focal point, endpoints and spread
+//! - Drop shadow, glow and inner shadow effects per layer
+//! - Single and double outline modes for dots and corners
+//! - Pattern (stripes, crosshatch, polka, noise) and image texture fills
+//! - Logo/image embedding sized against per-block error correction capacity
+//! - Halftone picture codes with exact module centers
+//! - Background images with fit modes, opacity, blur and a readability panel
 //! - Circle shape support
 //! - Multiple output formats (SVG, PNG, JPEG, WebP)
+//! - Restyling of existing QR code images with the exact same symbol
+//! - Scannability verification by decoding the rendered output
+//! - Color contrast validation with optional automatic adjustment
+//! - Named style presets and a theme registry
+//! - CSS color parsing (names, `rgb()`, `hsl()`, `hwb()`, `oklch()`) with
+//!   HSL/OKLCH conversions
 //!
 //! ## Example
 //!
@@ -35,22 +56,28 @@
 //! // qr.save("qr.png", OutputFormat::Png).unwrap();
 //! ```
 
+pub mod analysis;
 pub mod config;
 pub mod core;
 pub mod error;
 pub mod figures;
+pub mod payload;
 pub mod plugins;
+pub mod presets;
 pub mod rendering;
 pub mod types;
 pub mod utils;
 
 // Re-export main types at crate root for convenience
+pub use analysis::VerifyReport;
 pub use config::{
-    BackgroundOptions, Color, ColorStop, CornersDotOptions, CornersSquareOptions, DotsOptions,
-    Gradient, ImageOptions, QRCodeStylingBuilder, QRCodeStylingOptions, QROptions,
+    BackgroundOptions, Color, ColorStop, ContrastOptions, ContrastPolicy, CornersDotOptions,
+    CornersSquareOptions, DotsOptions, Gradient, ImageOptions, QRCodeStylingBuilder,
+    QRCodeStylingOptions, QROptions,
 };
 pub use core::QRCodeStyling;
-pub use error::{QRError, Result};
+pub use error::{PayloadError, QRError, Result};
+pub use payload::{Payload, PayloadType};
 pub use plugins::{BorderDecoration, BorderOptions, BorderPlugin, Position, QRBorderOptions};
 pub use types::{
     CornerDotType, CornerSquareType, DotType, ErrorCorrectionLevel, GradientType, Mode,
@@ -0,0 +1,373 @@
+//! vCard and MeCard contact payloads.
+
+use super::{split_escaped, unescape};
+use crate::error::{PayloadError, Result};
+
+/// Structured name components (vCard `N` / MeCard `N`).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StructuredName {
+    /// Family (last) name.
+    pub family: String,
+    /// Given (first) name.
+    pub given: String,
+    /// Additional (middle) names.
+    pub additional: String,
+    /// Honorific prefix.
+    pub prefix: String,
+    /// Honorific suffix.
+    pub suffix: String,
+}
+
+/// A telephone number with its type hints (e.g. `cell`, `work`).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Phone {
+    /// The number as written in the payload.
+    pub number: String,
+    /// Lowercased type parameters.
+    pub kinds: Vec<String>,
+}
+
+/// Contact information from a vCard or MeCard.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Contact {
+    /// Display name (vCard `FN`).
+    pub formatted_name: Option<String>,
+    /// Structured name.
+    pub name: Option<StructuredName>,
+    /// Organization.
+    pub organization: Option<String>,
+    /// Job title.
+    pub title: Option<String>,
+    /// Telephone numbers.
+    pub phones: Vec<Phone>,
+    /// Email addresses.
+    pub emails: Vec<String>,
+    /// Web links.
+    pub urls: Vec<String>,
+    /// Postal addresses, components joined with `", "`.
+    pub addresses: Vec<String>,
+    /// Birthday as written in the payload.
+    pub birthday: Option<String>,
+    /// Free-form note.
+    pub note: Option<String>,
+}
+
+impl Contact {
+    /// Parse a `BEGIN:VCARD` ... `END:VCARD` block (versions 2.1, 3.0 and 4.0).
+    pub fn parse_vcard(data: &str) -> Result<Self> {
+        const PAYLOAD: &str = "vCard";
+
+        let lines = unfold_lines(data);
+        let mut lines = lines
+            .iter()
+            .map(String::as_str)
+            .filter(|l| !l.trim().is_empty());
+
+        match lines.next() {
+            Some(first) if first.trim().eq_ignore_ascii_case("BEGIN:VCARD") => {}
+            _ => return Err(PayloadError::UnknownFormat.into()),
+        }
+
+        let mut contact = Contact::default();
+        let mut version = None;
+        let mut ended = false;
+
+        for line in lines {
+            let (head, value) = line
+                .split_once(':')
+                .ok_or_else(|| PayloadError::Malformed {
+                    payload: PAYLOAD,
+                    reason: format!("line {:?} has no `:` separator", line),
+                })?;
+
+            let mut head_parts = head.split(';');
+            let name = head_parts.next().unwrap_or_default();
+            // Strip an optional `group.` prefix.
+            let name = name.rsplit('.').next().unwrap_or(name).to_ascii_uppercase();
+            let params: Vec<&str> = head_parts.collect();
+
+            match name.as_str() {
+                "END" => {
+                    ended = true;
+                    break;
+                }
+                "VERSION" => {
+                    let value = value.trim();
+                    if !matches!(value, "2.1" | "3.0" | "4.0") {
+                        return Err(PayloadError::InvalidField {
+                            payload: PAYLOAD,
+                            field: "VERSION",
+                            value: value.to_string(),
+                            reason: "supported versions are 2.1, 3.0 and 4.0",
+                        }
+                        .into());
+                    }
+                    version = Some(value.to_string());
+                }
+                "FN" => contact.formatted_name = Some(unescape_text(value)),
+                "N" => {
+                    let parts: Vec<String> = split_escaped(value, ';')
+                        .into_iter()
+                        .map(unescape_text)
+                        .collect();
+                    let part = |i: usize| parts.get(i).cloned().unwrap_or_default();
+                    contact.name = Some(StructuredName {
+                        family: part(0),
+                        given: part(1),
+                        additional: part(2),
+                        prefix: part(3),
+                        suffix: part(4),
+                    });
+                }
+                "ORG" => contact.organization = Some(join_components(value, unescape_text)),
+                "TITLE" => contact.title = Some(unescape_text(value)),
+                "TEL" => contact.phones.push(Phone {
+                    number: unescape_text(value),
+                    kinds: type_params(&params),
+                }),
+                "EMAIL" => contact.emails.push(unescape_text(value)),
+                "URL" => contact.urls.push(unescape_text(value)),
+                "ADR" => contact
+                    .addresses
+                    .push(join_components(value, unescape_text)),
+                "BDAY" => contact.birthday = Some(unescape_text(value)),
+                "NOTE" => contact.note = Some(unescape_text(value)),
+                _ => {}
+            }
+        }
+
+        if !ended {
+            return Err(PayloadError::Malformed {
+                payload: PAYLOAD,
+                reason: "missing END:VCARD".to_string(),
+            }
+            .into());
+        }
+        if version.is_none() {
+            return Err(PayloadError::MissingField {
+                payload: PAYLOAD,
+                field: "VERSION",
+            }
+            .into());
+        }
+        if contact.formatted_name.is_none() && contact.name.is_none() {
+            return Err(PayloadError::MissingField {
+                payload: PAYLOAD,
+                field: "FN",
+            }
+            .into());
+        }
+
+        Ok(contact)
+    }
+
+    /// Parse a `MECARD:N:Doe,John;TEL:...;;` string.
+    pub fn parse_mecard(data: &str) -> Result<Self> {
+        const PAYLOAD: &str = "MeCard";
+
+        let body = data
+            .get(..7)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("MECARD:"))
+            .map(|_| &data[7..])
+            .ok_or(PayloadError::UnknownFormat)?;
+
+        let mut contact = Contact::default();
+
+        for field in split_escaped(body, ';') {
+            if field.trim().is_empty() {
+                continue;
+            }
+            let (key, value) = field
+                .split_once(':')
+                .ok_or_else(|| PayloadError::Malformed {
+                    payload: PAYLOAD,
+                    reason: format!("field {:?} has no `key:value` separator", field),
+                })?;
+
+            match key.trim().to_ascii_uppercase().as_str() {
+                "N" => {
+                    let parts: Vec<String> = split_escaped(value, ',')
+                        .into_iter()
+                        .map(unescape)
+                        .collect();
+                    let name = StructuredName {
+                        family: parts.first().cloned().unwrap_or_default(),
+                        given: parts.get(1).cloned().unwrap_or_default(),
+                        ..Default::default()
+                    };
+                    let formatted = [name.given.as_str(), name.family.as_str()]
+                        .iter()
+                        .filter(|s| !s.is_empty())
+                        .copied()
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    contact.formatted_name = Some(formatted);
+                    contact.name = Some(name);
+                }
+                "TEL" | "TEL-AV" => contact.phones.push(Phone {
+                    number: unescape(value),
+                    kinds: Vec::new(),
+                }),
+                "EMAIL" => contact.emails.push(unescape(value)),
+                "URL" => contact.urls.push(unescape(value)),
+                "ADR" => contact.addresses.push(join_components(value, unescape)),
+                "ORG" => contact.organization = Some(unescape(value)),
+                "TITLE" => contact.title = Some(unescape(value)),
+                "BDAY" => {
+                    let value = unescape(value);
+                    if value.len() != 8 || !value.chars().all(|c| c.is_ascii_digit()) {
+                        return Err(PayloadError::InvalidField {
+                            payload: PAYLOAD,
+                            field: "BDAY",
+                            value,
+                            reason: "expected an 8-digit YYYYMMDD date",
+                        }
+                        .into());
+                    }
+                    contact.birthday = Some(value);
+                }
+                "NOTE" => contact.note = Some(unescape(value)),
+                _ => {}
+            }
+        }
+
+        if contact.name.is_none() {
+            return Err(PayloadError::MissingField {
+                payload: PAYLOAD,
+                field: "N",
+            }
+            .into());
+        }
+
+        Ok(contact)
+    }
+}
+
+/// Split into lines and undo RFC 6350 line folding.
+fn unfold_lines(data: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in data.split('\n') {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        if let Some(continuation) = raw.strip_prefix([' ', '\t']) {
+            if let Some(last) = lines.last_mut() {
+                last.push_str(continuation);
+                continue;
+            }
+        }
+        lines.push(raw.to_string());
+    }
+    lines
+}
+
+/// Remove vCard text escapes, where `\n` also stands for a newline.
+fn unescape_text(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') | Some('N') => result.push('\n'),
+                Some(other) => result.push(other),
+                None => {}
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Join `;`-separated structured components, dropping empty ones.
+fn join_components(value: &str, unescape: fn(&str) -> String) -> String {
+    split_escaped(value, ';')
+        .into_iter()
+        .map(unescape)
+        .filter(|part| !part.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Collect `TYPE=` parameters (and bare vCard 2.1 types) in lowercase.
+fn type_params(params: &[&str]) -> Vec<String> {
+    params
+        .iter()
+        .flat_map(|param| {
+            let value = match param.split_once('=') {
+                Some((key, value)) if key.eq_ignore_ascii_case("TYPE") => value,
+                Some(_) => "",
+                None => param,
+            };
+            value
+                .trim_matches('"')
+                .split(',')
+                .filter(|t| !t.is_empty())
+                .map(|t| t.to_ascii_lowercase())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::QRError;
+
+    #[test]
+    fn test_parse_vcard() {
+        let data = "BEGIN:VCARD\r\nVERSION:3.0\r\nN:Doe;John;;Dr.;\r\nFN:Dr. John Doe\r\n\
+                    ORG:Acme\\, Inc.;R&D\r\nTEL;TYPE=cell,voice:+1 555 0100\r\n\
+                    EMAIL:john@example.com\r\nNOTE:line one\\nline\r\n  two\r\nEND:VCARD";
+        let contact = Contact::parse_vcard(data).unwrap();
+        assert_eq!(contact.formatted_name.as_deref(), Some("Dr. John Doe"));
+        assert_eq!(contact.name.as_ref().unwrap().given, "John");
+        assert_eq!(contact.name.as_ref().unwrap().prefix, "Dr.");
+        assert_eq!(contact.organization.as_deref(), Some("Acme, Inc., R&D"));
+        assert_eq!(contact.phones[0].kinds, vec!["cell", "voice"]);
+        assert_eq!(contact.emails, vec!["john@example.com"]);
+        assert_eq!(contact.note.as_deref(), Some("line one\nline two"));
+    }
+
+    #[test]
+    fn test_vcard_errors() {
+        assert!(matches!(
+            Contact::parse_vcard("BEGIN:VCARD\nVERSION:3.0\nFN:X\n"),
+            Err(QRError::InvalidPayload(PayloadError::Malformed { .. }))
+        ));
+        assert!(matches!(
+            Contact::parse_vcard("BEGIN:VCARD\nFN:X\nEND:VCARD"),
+            Err(QRError::InvalidPayload(PayloadError::MissingField {
+                field: "VERSION",
+                ..
+            }))
+        ));
+        assert!(matches!(
+            Contact::parse_vcard("BEGIN:VCARD\nVERSION:5.0\nFN:X\nEND:VCARD"),
+            Err(QRError::InvalidPayload(PayloadError::InvalidField {
+                field: "VERSION",
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_parse_mecard() {
+        let data = r"MECARD:N:Doe,John;TEL:+15550100;EMAIL:john@example.com;BDAY:19700101;ADR:1 Main St\, Springfield;;";
+        let contact = Contact::parse_mecard(data).unwrap();
+        assert_eq!(contact.formatted_name.as_deref(), Some("John Doe"));
+        assert_eq!(contact.phones[0].number, "+15550100");
+        assert_eq!(contact.birthday.as_deref(), Some("19700101"));
+        assert_eq!(contact.addresses, vec!["1 Main St, Springfield"]);
+
+        assert!(matches!(
+            Contact::parse_mecard("MECARD:TEL:1;;"),
+            Err(QRError::InvalidPayload(PayloadError::MissingField {
+                field: "N",
+                ..
+            }))
+        ));
+    }
+}
@@ -0,0 +1,121 @@
+//! `mailto:` email URIs (RFC 6068).
+
+use super::uri::{parse_query, percent_decode};
+use crate::error::{PayloadError, Result};
+
+const PAYLOAD: &str = "mailto";
+
+/// Email draft.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmailMessage {
+    /// Primary recipients.
+    pub to: Vec<String>,
+    /// Carbon-copy recipients.
+    pub cc: Vec<String>,
+    /// Blind carbon-copy recipients.
+    pub bcc: Vec<String>,
+    /// Subject line.
+    pub subject: Option<String>,
+    /// Message body.
+    pub body: Option<String>,
+}
+
+impl EmailMessage {
+    /// Parse a `mailto:a@b.c?subject=...&body=...` URI.
+    pub fn parse(data: &str) -> Result<Self> {
+        let rest = data
+            .get(..7)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("mailto:"))
+            .map(|_| &data[7..])
+            .ok_or(PayloadError::UnknownFormat)?;
+
+        let (to, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let mut message = EmailMessage {
+            to: parse_addresses("to", &percent_decode(to, false, PAYLOAD, "to")?)?,
+            ..Default::default()
+        };
+
+        for (key, value) in parse_query(query, false, PAYLOAD)? {
+            match key.as_str() {
+                "to" => message.to.extend(parse_addresses("to", &value)?),
+                "cc" => message.cc.extend(parse_addresses("cc", &value)?),
+                "bcc" => message.bcc.extend(parse_addresses("bcc", &value)?),
+                "subject" => message.subject = Some(value),
+                "body" => message.body = Some(value),
+                _ => {}
+            }
+        }
+
+        if message.to.is_empty() && message.cc.is_empty() && message.bcc.is_empty() {
+            return Err(PayloadError::MissingField {
+                payload: PAYLOAD,
+                field: "to",
+            }
+            .into());
+        }
+
+        Ok(message)
+    }
+}
+
+/// Split a comma-separated address list and sanity-check each address.
+fn parse_addresses(field: &'static str, value: &str) -> Result<Vec<String>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|address| !address.is_empty())
+        .map(|address| {
+            let valid = address
+                .rsplit_once('@')
+                .is_some_and(|(local, domain)| !local.is_empty() && !domain.is_empty());
+            if valid {
+                Ok(address.to_string())
+            } else {
+                Err(PayloadError::InvalidField {
+                    payload: PAYLOAD,
+                    field,
+                    value: address.to_string(),
+                    reason: "expected an address of the form local@domain",
+                }
+                .into())
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::QRError;
+
+    #[test]
+    fn test_parse_mailto() {
+        let message = EmailMessage::parse(
+            "mailto:a@example.com,b@example.com?cc=c@example.com&subject=Hello%20there&body=1+1%3D2",
+        )
+        .unwrap();
+        assert_eq!(message.to, vec!["a@example.com", "b@example.com"]);
+        assert_eq!(message.cc, vec!["c@example.com"]);
+        assert_eq!(message.subject.as_deref(), Some("Hello there"));
+        assert_eq!(message.body.as_deref(), Some("1+1=2"));
+    }
+
+    #[test]
+    fn test_invalid_addresses() {
+        assert!(matches!(
+            EmailMessage::parse("mailto:?subject=x"),
+            Err(QRError::InvalidPayload(PayloadError::MissingField {
+                field: "to",
+                ..
+            }))
+        ));
+        assert!(matches!(
+            EmailMessage::parse("mailto:nobody"),
+            Err(QRError::InvalidPayload(PayloadError::InvalidField {
+                field: "to",
+                ..
+            }))
+        ));
+    }
+}
@@ -0,0 +1,299 @@
+//! EMVCo merchant-presented payment payloads (EMV QRCPS-MPM).
+
+use crate::error::{PayloadError, Result};
+
+const PAYLOAD: &str = "EMVCo";
+
+/// A single `ID LEN VALUE` data object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmvField {
+    /// Two-digit data object ID.
+    pub id: u8,
+    /// Raw value.
+    pub value: String,
+}
+
+impl EmvField {
+    /// Parse the value as a nested template of data objects.
+    pub fn subfields(&self) -> Result<Vec<EmvField>> {
+        parse_objects(&self.value)
+    }
+}
+
+/// Point of initiation method (ID `01`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum PointOfInitiation {
+    /// Code reused for multiple transactions (`11`).
+    Static,
+    /// Code generated for one transaction (`12`).
+    Dynamic,
+}
+
+/// EMVCo merchant-presented payment payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmvPayload {
+    /// Point of initiation method.
+    pub point_of_initiation: Option<PointOfInitiation>,
+    /// Merchant account information objects (IDs `02` to `51`).
+    pub merchant_accounts: Vec<EmvField>,
+    /// ISO 18245 merchant category code.
+    pub merchant_category_code: String,
+    /// ISO 4217 numeric currency code.
+    pub currency: String,
+    /// Transaction amount as written.
+    pub amount: Option<String>,
+    /// ISO 3166-1 alpha-2 country code.
+    pub country_code: String,
+    /// Merchant name.
+    pub merchant_name: String,
+    /// Merchant city.
+    pub merchant_city: String,
+    /// Postal code.
+    pub postal_code: Option<String>,
+    /// Additional data template (ID `62`) contents.
+    pub additional_data: Vec<EmvField>,
+    /// CRC-16 from ID `63`.
+    pub crc: u16,
+    /// All top-level data objects in payload order.
+    pub fields: Vec<EmvField>,
+}
+
+impl EmvPayload {
+    /// Parse and checksum-verify an EMVCo payload string.
+    pub fn parse(data: &str) -> Result<Self> {
+        let fields = parse_objects(data)?;
+
+        match fields.first() {
+            Some(EmvField { id: 0, value }) if value == "01" => {}
+            Some(EmvField { id: 0, value }) => {
+                return Err(invalid("00", value, "payload format indicator must be 01"))
+            }
+            _ => return Err(PayloadError::UnknownFormat.into()),
+        }
+
+        let crc_field = match fields.last() {
+            Some(field) if field.id == 63 => field,
+            _ => {
+                return Err(PayloadError::Malformed {
+                    payload: PAYLOAD,
+                    reason: "the CRC (ID 63) must be the last data object".to_string(),
+                }
+                .into())
+            }
+        };
+        let crc = u16::from_str_radix(&crc_field.value, 16)
+            .ok()
+            .filter(|_| crc_field.value.len() == 4)
+            .ok_or_else(|| invalid("63", &crc_field.value, "expected 4 hexadecimal digits"))?;
+
+        // The CRC covers everything up to and including the "6304" header.
+        let covered = &data[..data.len() - 4];
+        let expected = crc16_ccitt(covered.as_bytes());
+        if expected != crc {
+            return Err(PayloadError::ChecksumMismatch {
+                payload: PAYLOAD,
+                expected: format!("{:04X}", expected),
+                found: crc_field.value.to_uppercase(),
+            }
+            .into());
+        }
+
+        let find = |id: u8| fields.iter().find(|f| f.id == id).map(|f| f.value.clone());
+        let required = |id: u8, field: &'static str| {
+            find(id).ok_or(PayloadError::MissingField {
+                payload: PAYLOAD,
+                field,
+            })
+        };
+
+        let point_of_initiation = match find(1).as_deref() {
+            None => None,
+            Some("11") => Some(PointOfInitiation::Static),
+            Some("12") => Some(PointOfInitiation::Dynamic),
+            Some(other) => return Err(invalid("01", other, "expected 11 or 12")),
+        };
+
+        let merchant_accounts: Vec<EmvField> = fields
+            .iter()
+            .filter(|f| (2..=51).contains(&f.id))
+            .cloned()
+            .collect();
+        if merchant_accounts.is_empty() {
+            return Err(PayloadError::MissingField {
+                payload: PAYLOAD,
+                field: "merchant account information (02-51)",
+            }
+            .into());
+        }
+
+        let merchant_category_code = required(52, "52")?;
+        if merchant_category_code.len() != 4
+            || !merchant_category_code.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid("52", &merchant_category_code, "expected 4 digits"));
+        }
+
+        let currency = required(53, "53")?;
+        if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_digit()) {
+            return Err(invalid("53", &currency, "expected a 3-digit ISO 4217 code"));
+        }
+
+        let amount = find(54);
+        if let Some(amount) = &amount {
+            let valid = amount.parse::<f64>().is_ok_and(|a| a > 0.0)
+                && amount.chars().all(|c| c.is_ascii_digit() || c == '.');
+            if !valid {
+                return Err(invalid("54", amount, "expected a positive decimal amount"));
+            }
+        }
+
+        let country_code = required(58, "58")?;
+        if country_code.len() != 2 || !country_code.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(invalid(
+                "58",
+                &country_code,
+                "expected an ISO 3166-1 alpha-2 code",
+            ));
+        }
+
+        let additional_data = match fields.iter().find(|f| f.id == 62) {
+            Some(field) => field.subfields()?,
+            None => Vec::new(),
+        };
+
+        Ok(Self {
+            point_of_initiation,
+            merchant_accounts,
+            merchant_category_code,
+            currency,
+            amount,
+            country_code,
+            merchant_name: required(59, "59")?,
+            merchant_city: required(60, "60")?,
+            postal_code: find(61),
+            additional_data,
+            crc,
+            fields,
+        })
+    }
+}
+
+/// Split a string into `ID(2) LEN(2) VALUE` objects.
+fn parse_objects(data: &str) -> Result<Vec<EmvField>> {
+    let chars: Vec<char> = data.chars().collect();
+    let mut fields = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if i + 4 > chars.len() {
+            return Err(PayloadError::Malformed {
+                payload: PAYLOAD,
+                reason: format!("truncated data object header at offset {}", i),
+            }
+            .into());
+        }
+        let id: String = chars[i..i + 2].iter().collect();
+        let len: String = chars[i + 2..i + 4].iter().collect();
+        let id: u8 = id.parse().map_err(|_| PayloadError::Malformed {
+            payload: PAYLOAD,
+            reason: format!("invalid data object ID {:?} at offset {}", id, i),
+        })?;
+        let len: usize = len.parse().map_err(|_| PayloadError::Malformed {
+            payload: PAYLOAD,
+            reason: format!("invalid length {:?} for ID {:02}", len, id),
+        })?;
+
+        let start = i + 4;
+        if start + len > chars.len() {
+            return Err(PayloadError::Malformed {
+                payload: PAYLOAD,
+                reason: format!("ID {:02} declares {} characters past the end", id, len),
+            }
+            .into());
+        }
+        fields.push(EmvField {
+            id,
+            value: chars[start..start + len].iter().collect(),
+        });
+        i = start + len;
+    }
+
+    Ok(fields)
+}
+
+/// CRC-16/CCITT-FALSE as required by the EMVCo specification.
+fn crc16_ccitt(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+fn invalid(field: &'static str, value: &str, reason: &'static str) -> crate::error::QRError {
+    PayloadError::InvalidField {
+        payload: PAYLOAD,
+        field,
+        value: value.to_string(),
+        reason,
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::QRError;
+
+    fn with_crc(body: &str) -> String {
+        let covered = format!("{}6304", body);
+        format!("{}{:04X}", covered, crc16_ccitt(covered.as_bytes()))
+    }
+
+    const BODY: &str = "000201010211\
+                        26220008COM.TEST01061234AB\
+                        52045812530398654041.505802SG\
+                        5909Coffee Co6009Singapore\
+                        62070703A01";
+
+    #[test]
+    fn test_parse_emv() {
+        let payload = EmvPayload::parse(&with_crc(BODY)).unwrap();
+        assert_eq!(payload.point_of_initiation, Some(PointOfInitiation::Static));
+        assert_eq!(payload.merchant_accounts[0].id, 26);
+        assert_eq!(payload.merchant_category_code, "5812");
+        assert_eq!(payload.currency, "986");
+        assert_eq!(payload.amount.as_deref(), Some("1.50"));
+        assert_eq!(payload.merchant_name, "Coffee Co");
+        assert_eq!(payload.additional_data[0].id, 7);
+        assert_eq!(payload.additional_data[0].value, "A01");
+    }
+
+    #[test]
+    fn test_crc_mismatch() {
+        let mut data = with_crc(BODY);
+        data.replace_range(data.len() - 4.., "0000");
+        assert!(matches!(
+            EmvPayload::parse(&data),
+            Err(QRError::InvalidPayload(
+                PayloadError::ChecksumMismatch { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_crc_reference_value() {
+        assert_eq!(crc16_ccitt(b"123456789"), 0x29B1);
+    }
+}
@@ -0,0 +1,299 @@
+//! `BCD` EPC SEPA credit transfer payloads (EPC069-12).
+
+use crate::error::{PayloadError, Result};
+
+const PAYLOAD: &str = "EPC";
+
+/// Transfer amount, stored in minor units to avoid rounding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EpcAmount {
+    /// ISO 4217 currency code (always `EUR` for SEPA).
+    pub currency: String,
+    /// Amount in cents.
+    pub cents: u64,
+}
+
+/// SEPA credit transfer request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EpcTransfer {
+    /// Format version (1 or 2).
+    pub version: u8,
+    /// Character set code (1 = UTF-8 ... 8 = ISO 8859-15).
+    pub character_set: u8,
+    /// Beneficiary BIC (optional from version 2).
+    pub bic: Option<String>,
+    /// Beneficiary name.
+    pub name: String,
+    /// Beneficiary IBAN, without spaces.
+    pub iban: String,
+    /// Requested amount.
+    pub amount: Option<EpcAmount>,
+    /// Four-letter purpose code.
+    pub purpose: Option<String>,
+    /// Structured creditor reference.
+    pub reference: Option<String>,
+    /// Unstructured remittance text.
+    pub text: Option<String>,
+    /// Beneficiary to originator information.
+    pub information: Option<String>,
+}
+
+impl EpcTransfer {
+    /// Parse a newline-separated `BCD` payload.
+    pub fn parse(data: &str) -> Result<Self> {
+        let lines: Vec<&str> = data
+            .split('\n')
+            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+            .collect();
+
+        if lines.first() != Some(&"BCD") {
+            return Err(PayloadError::UnknownFormat.into());
+        }
+        if lines.len() > 12 && lines[12..].iter().any(|l| !l.is_empty()) {
+            return Err(PayloadError::Malformed {
+                payload: PAYLOAD,
+                reason: format!("expected at most 12 lines, found {}", lines.len()),
+            }
+            .into());
+        }
+
+        let line = |i: usize| lines.get(i).map(|l| l.trim()).filter(|l| !l.is_empty());
+        let required = |i: usize, field: &'static str| {
+            line(i).ok_or(PayloadError::MissingField {
+                payload: PAYLOAD,
+                field,
+            })
+        };
+
+        let version = match required(1, "version")? {
+            "001" => 1,
+            "002" => 2,
+            other => return Err(invalid("version", other, "expected 001 or 002")),
+        };
+
+        let character_set = required(2, "character set")?
+            .parse::<u8>()
+            .ok()
+            .filter(|c| (1..=8).contains(c))
+            .ok_or_else(|| invalid("character set", lines[2], "expected a digit from 1 to 8"))?;
+
+        let identification = required(3, "identification")?;
+        if identification != "SCT" {
+            return Err(invalid("identification", identification, "expected SCT"));
+        }
+
+        let bic = line(4).map(str::to_string);
+        match &bic {
+            Some(bic) => validate_bic(bic)?,
+            None if version == 1 => {
+                return Err(PayloadError::MissingField {
+                    payload: PAYLOAD,
+                    field: "BIC",
+                }
+                .into())
+            }
+            None => {}
+        }
+
+        let name = required(5, "name")?;
+        check_length("name", name, 70)?;
+
+        let iban: String = required(6, "IBAN")?
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        validate_iban(&iban)?;
+
+        let amount = line(7).map(parse_amount).transpose()?;
+
+        let purpose = line(8).map(str::to_string);
+        if let Some(purpose) = &purpose {
+            if purpose.len() != 4 || !purpose.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(invalid("purpose", purpose, "expected a 4-character code"));
+            }
+        }
+
+        let reference = line(9).map(str::to_string);
+        let text = line(10).map(str::to_string);
+        if let Some(reference) = &reference {
+            check_length("reference", reference, 35)?;
+        }
+        if let Some(text) = &text {
+            check_length("text", text, 140)?;
+        }
+        if reference.is_some() && text.is_some() {
+            return Err(PayloadError::Malformed {
+                payload: PAYLOAD,
+                reason: "structured reference and remittance text are mutually exclusive"
+                    .to_string(),
+            }
+            .into());
+        }
+
+        let information = line(11).map(str::to_string);
+        if let Some(information) = &information {
+            check_length("information", information, 70)?;
+        }
+
+        Ok(Self {
+            version,
+            character_set,
+            bic,
+            name: name.to_string(),
+            iban,
+            amount,
+            purpose,
+            reference,
+            text,
+            information,
+        })
+    }
+}
+
+fn invalid(field: &'static str, value: &str, reason: &'static str) -> crate::error::QRError {
+    PayloadError::InvalidField {
+        payload: PAYLOAD,
+        field,
+        value: value.to_string(),
+        reason,
+    }
+    .into()
+}
+
+fn check_length(field: &'static str, value: &str, max: usize) -> Result<()> {
+    if value.chars().count() > max {
+        return Err(invalid(field, value, "value exceeds the maximum length"));
+    }
+    Ok(())
+}
+
+fn validate_bic(bic: &str) -> Result<()> {
+    let valid = (bic.len() == 8 || bic.len() == 11)
+        && bic.chars().all(|c| c.is_ascii_alphanumeric())
+        && bic[..6].chars().all(|c| c.is_ascii_alphabetic());
+    if !valid {
+        return Err(invalid("BIC", bic, "expected an 8 or 11 character BIC"));
+    }
+    Ok(())
+}
+
+/// Validate IBAN structure and its ISO 7064 mod-97 check digits.
+fn validate_iban(iban: &str) -> Result<()> {
+    let structure_ok = (15..=34).contains(&iban.len())
+        && iban.chars().all(|c| c.is_ascii_alphanumeric())
+        && iban[..2].chars().all(|c| c.is_ascii_alphabetic())
+        && iban[2..4].chars().all(|c| c.is_ascii_digit());
+    if !structure_ok {
+        return Err(invalid(
+            "IBAN",
+            iban,
+            "expected country code, check digits and account",
+        ));
+    }
+
+    let rearranged = iban[4..].chars().chain(iban[..4].chars());
+    let mut remainder: u32 = 0;
+    for c in rearranged {
+        let value = c.to_ascii_uppercase().to_digit(36).unwrap_or(0);
+        remainder = if value >= 10 {
+            (remainder * 100 + value) % 97
+        } else {
+            (remainder * 10 + value) % 97
+        };
+    }
+
+    if remainder != 1 {
+        return Err(PayloadError::ChecksumMismatch {
+            payload: PAYLOAD,
+            expected: "IBAN mod-97 remainder 1".to_string(),
+            found: format!("remainder {}", remainder),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Parse `EUR123.45`.
+fn parse_amount(value: &str) -> Result<EpcAmount> {
+    let reason = "expected EUR followed by 0.01 to 999999999.99";
+    if value.len() < 4 || !value.is_char_boundary(3) {
+        return Err(invalid("amount", value, reason));
+    }
+    let (currency, number) = value.split_at(3);
+    if currency != "EUR" {
+        return Err(invalid("amount", value, reason));
+    }
+
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    let digits_ok = !whole.is_empty()
+        && whole.len() <= 9
+        && fraction.len() <= 2
+        && whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit());
+    if !digits_ok {
+        return Err(invalid("amount", value, reason));
+    }
+
+    let whole: u64 = whole
+        .parse()
+        .map_err(|_| invalid("amount", value, reason))?;
+    let fraction: u64 = format!("{:0<2}", fraction)
+        .parse()
+        .map_err(|_| invalid("amount", value, reason))?;
+    let cents = whole * 100 + fraction;
+    if cents == 0 {
+        return Err(invalid("amount", value, reason));
+    }
+
+    Ok(EpcAmount {
+        currency: currency.to_string(),
+        cents,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::QRError;
+
+    const SAMPLE: &str = "BCD\n002\n1\nSCT\nBFSWDE33BER\nWikimedia Foerdergesellschaft\n\
+                          DE33100205000001194700\nEUR123.4\n\n\nSpende fuer Wikipedia";
+
+    #[test]
+    fn test_parse_transfer() {
+        let transfer = EpcTransfer::parse(SAMPLE).unwrap();
+        assert_eq!(transfer.version, 2);
+        assert_eq!(transfer.bic.as_deref(), Some("BFSWDE33BER"));
+        assert_eq!(transfer.iban, "DE33100205000001194700");
+        assert_eq!(transfer.amount.as_ref().unwrap().cents, 12340);
+        assert_eq!(transfer.text.as_deref(), Some("Spende fuer Wikipedia"));
+        assert_eq!(transfer.reference, None);
+    }
+
+    #[test]
+    fn test_iban_checksum() {
+        let data = SAMPLE.replace("DE33", "DE34");
+        assert!(matches!(
+            EpcTransfer::parse(&data),
+            Err(QRError::InvalidPayload(
+                PayloadError::ChecksumMismatch { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_amount() {
+        let data = SAMPLE.replace("EUR123.4", "USD10");
+        assert!(matches!(
+            EpcTransfer::parse(&data),
+            Err(QRError::InvalidPayload(PayloadError::InvalidField {
+                field: "amount",
+                ..
+            }))
+        ));
+    }
+}
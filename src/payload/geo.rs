@@ -0,0 +1,151 @@
+//! `geo:` location URIs (RFC 5870).
+
+use super::uri::{parse_query, percent_decode};
+use crate::error::{PayloadError, Result};
+
+const PAYLOAD: &str = "geo";
+
+/// Geographic location.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeoLocation {
+    /// Latitude in degrees (-90 to 90).
+    pub latitude: f64,
+    /// Longitude in degrees (-180 to 180).
+    pub longitude: f64,
+    /// Altitude in meters.
+    pub altitude: Option<f64>,
+    /// Location uncertainty in meters (`;u=` parameter).
+    pub uncertainty: Option<f64>,
+    /// Search query (`?q=` extension used by mobile map apps).
+    pub query: Option<String>,
+}
+
+impl GeoLocation {
+    /// Parse a `geo:lat,lon[,alt][;u=unc][?q=query]` URI.
+    pub fn parse(data: &str) -> Result<Self> {
+        let rest = data
+            .get(..4)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("geo:"))
+            .map(|_| &data[4..])
+            .ok_or(PayloadError::UnknownFormat)?;
+
+        let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let mut params = path.split(';');
+        let coordinates: Vec<&str> = params.next().unwrap_or_default().split(',').collect();
+
+        if coordinates.len() < 2 || coordinates.len() > 3 {
+            return Err(PayloadError::Malformed {
+                payload: PAYLOAD,
+                reason: format!(
+                    "expected `lat,lon` or `lat,lon,alt`, found {} coordinates",
+                    coordinates.len()
+                ),
+            }
+            .into());
+        }
+
+        let latitude = parse_number("latitude", coordinates[0])?;
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(invalid(
+                "latitude",
+                coordinates[0],
+                "must be between -90 and 90",
+            ));
+        }
+        let longitude = parse_number("longitude", coordinates[1])?;
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(invalid(
+                "longitude",
+                coordinates[1],
+                "must be between -180 and 180",
+            ));
+        }
+        let altitude = coordinates
+            .get(2)
+            .map(|a| parse_number("altitude", a))
+            .transpose()?;
+
+        let mut uncertainty = None;
+        for param in params {
+            let (key, value) = param.split_once('=').unwrap_or((param, ""));
+            match key.to_ascii_lowercase().as_str() {
+                "u" => {
+                    let u = parse_number("u", value)?;
+                    if u < 0.0 {
+                        return Err(invalid("u", value, "must not be negative"));
+                    }
+                    uncertainty = Some(u);
+                }
+                "crs" if !value.eq_ignore_ascii_case("wgs84") => {
+                    return Err(invalid("crs", value, "only wgs84 is supported"));
+                }
+                _ => {}
+            }
+        }
+
+        let query = parse_query(query, true, PAYLOAD)?
+            .into_iter()
+            .find(|(key, _)| key == "q")
+            .map(|(_, value)| value);
+
+        Ok(Self {
+            latitude,
+            longitude,
+            altitude,
+            uncertainty,
+            query,
+        })
+    }
+}
+
+fn parse_number(field: &'static str, value: &str) -> Result<f64> {
+    let decoded = percent_decode(value, false, PAYLOAD, field)?;
+    decoded
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|n| n.is_finite())
+        .ok_or_else(|| invalid(field, value, "expected a decimal number"))
+}
+
+fn invalid(field: &'static str, value: &str, reason: &'static str) -> crate::error::QRError {
+    PayloadError::InvalidField {
+        payload: PAYLOAD,
+        field,
+        value: value.to_string(),
+        reason,
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::QRError;
+
+    #[test]
+    fn test_parse_geo() {
+        let geo = GeoLocation::parse("geo:48.2010,16.3695,183;u=40?q=Vienna").unwrap();
+        assert_eq!(geo.latitude, 48.2010);
+        assert_eq!(geo.longitude, 16.3695);
+        assert_eq!(geo.altitude, Some(183.0));
+        assert_eq!(geo.uncertainty, Some(40.0));
+        assert_eq!(geo.query.as_deref(), Some("Vienna"));
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert!(matches!(
+            GeoLocation::parse("geo:91,0"),
+            Err(QRError::InvalidPayload(PayloadError::InvalidField {
+                field: "latitude",
+                ..
+            }))
+        ));
+        assert!(matches!(
+            GeoLocation::parse("geo:1"),
+            Err(QRError::InvalidPayload(PayloadError::Malformed { .. }))
+        ));
+    }
+}
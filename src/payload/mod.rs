@@ -0,0 +1,252 @@
+//! Parsing of decoded QR code payload strings into typed structures.
+//!
+//! Scanned QR codes usually carry one of a handful of well-known text
+//! formats. [`Payload::parse`] detects the format from its prefix and turns
+//! the string into the matching struct, reporting precise errors through
+//! [`PayloadError`].
+//!
+//! ```rust
+//! use qr_code_styling::payload::{Payload, PayloadType};
+//!
+//! let data = "WIFI:T:WPA;S:Office;P:secret;;";
+//! assert_eq!(PayloadType::detect(data), PayloadType::Wifi);
+//!
+//! match Payload::parse(data).unwrap() {
+//!     Payload::Wifi(network) => assert_eq!(network.ssid, "Office"),
+//!     _ => unreachable!(),
+//! }
+//! ```
+
+mod contact;
+mod email;
+mod emv;
+mod epc;
+mod geo;
+mod otp;
+mod uri;
+mod wifi;
+
+pub use contact::{Contact, Phone, StructuredName};
+pub use email::EmailMessage;
+pub use emv::{EmvField, EmvPayload, PointOfInitiation};
+pub use epc::{EpcAmount, EpcTransfer};
+pub use geo::GeoLocation;
+pub use otp::{OtpAlgorithm, OtpAuth, OtpKind};
+pub use wifi::{WifiNetwork, WifiSecurity};
+
+use crate::error::{PayloadError, Result};
+
+/// Known payload formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum PayloadType {
+    /// `WIFI:` network credentials.
+    Wifi,
+    /// `BEGIN:VCARD` contact card.
+    VCard,
+    /// `MECARD:` contact card.
+    MeCard,
+    /// `BCD` EPC SEPA credit transfer.
+    Epc,
+    /// `otpauth://` one-time password provisioning URI.
+    Otp,
+    /// `geo:` location URI.
+    Geo,
+    /// `mailto:` email URI.
+    Email,
+    /// EMVCo merchant-presented payment payload.
+    Emv,
+    /// `http://` or `https://` link.
+    Url,
+    /// Anything else.
+    Text,
+}
+
+impl PayloadType {
+    /// Detect the payload type from its prefix.
+    pub fn detect(data: &str) -> PayloadType {
+        let data = data.trim_start_matches('\u{feff}');
+
+        if starts_with_ignore_case(data, "WIFI:") {
+            PayloadType::Wifi
+        } else if starts_with_ignore_case(data, "BEGIN:VCARD") {
+            PayloadType::VCard
+        } else if starts_with_ignore_case(data, "MECARD:") {
+            PayloadType::MeCard
+        } else if data.starts_with("BCD\n") || data.starts_with("BCD\r\n") {
+            PayloadType::Epc
+        } else if starts_with_ignore_case(data, "otpauth://") {
+            PayloadType::Otp
+        } else if starts_with_ignore_case(data, "geo:") {
+            PayloadType::Geo
+        } else if starts_with_ignore_case(data, "mailto:") {
+            PayloadType::Email
+        } else if data.starts_with("000201") {
+            PayloadType::Emv
+        } else if starts_with_ignore_case(data, "http://")
+            || starts_with_ignore_case(data, "https://")
+        {
+            PayloadType::Url
+        } else {
+            PayloadType::Text
+        }
+    }
+}
+
+/// A parsed QR code payload.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Payload {
+    /// Wi-Fi network credentials.
+    Wifi(WifiNetwork),
+    /// Contact from a vCard.
+    VCard(Contact),
+    /// Contact from a MeCard.
+    MeCard(Contact),
+    /// EPC SEPA credit transfer.
+    Epc(EpcTransfer),
+    /// One-time password provisioning.
+    Otp(OtpAuth),
+    /// Geographic location.
+    Geo(GeoLocation),
+    /// Email draft.
+    Email(EmailMessage),
+    /// EMVCo merchant payment.
+    Emv(EmvPayload),
+    /// Web link.
+    Url(String),
+    /// Plain text.
+    Text(String),
+}
+
+impl Payload {
+    /// Detect the payload type and parse it.
+    ///
+    /// Unrecognized data is returned as [`Payload::Text`].
+    pub fn parse(data: &str) -> Result<Payload> {
+        Self::parse_as(data, PayloadType::detect(data))
+    }
+
+    /// Parse the payload as a specific type, bypassing detection.
+    pub fn parse_as(data: &str, payload_type: PayloadType) -> Result<Payload> {
+        let data = data.trim_start_matches('\u{feff}');
+
+        Ok(match payload_type {
+            PayloadType::Wifi => Payload::Wifi(WifiNetwork::parse(data)?),
+            PayloadType::VCard => Payload::VCard(Contact::parse_vcard(data)?),
+            PayloadType::MeCard => Payload::MeCard(Contact::parse_mecard(data)?),
+            PayloadType::Epc => Payload::Epc(EpcTransfer::parse(data)?),
+            PayloadType::Otp => Payload::Otp(OtpAuth::parse(data)?),
+            PayloadType::Geo => Payload::Geo(GeoLocation::parse(data)?),
+            PayloadType::Email => Payload::Email(EmailMessage::parse(data)?),
+            PayloadType::Emv => Payload::Emv(EmvPayload::parse(data)?),
+            PayloadType::Url => {
+                if PayloadType::detect(data) != PayloadType::Url {
+                    return Err(PayloadError::UnknownFormat.into());
+                }
+                Payload::Url(data.to_string())
+            }
+            PayloadType::Text => Payload::Text(data.to_string()),
+        })
+    }
+
+    /// Get the type of this payload.
+    pub fn payload_type(&self) -> PayloadType {
+        match self {
+            Payload::Wifi(_) => PayloadType::Wifi,
+            Payload::VCard(_) => PayloadType::VCard,
+            Payload::MeCard(_) => PayloadType::MeCard,
+            Payload::Epc(_) => PayloadType::Epc,
+            Payload::Otp(_) => PayloadType::Otp,
+            Payload::Geo(_) => PayloadType::Geo,
+            Payload::Email(_) => PayloadType::Email,
+            Payload::Emv(_) => PayloadType::Emv,
+            Payload::Url(_) => PayloadType::Url,
+            Payload::Text(_) => PayloadType::Text,
+        }
+    }
+}
+
+/// Case-insensitive ASCII prefix check.
+fn starts_with_ignore_case(data: &str, prefix: &str) -> bool {
+    data.len() >= prefix.len()
+        && data.as_bytes()[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+}
+
+/// Split `data` on `separator`, honouring backslash escapes.
+///
+/// Escape sequences are kept intact; use [`unescape`] on each part.
+fn split_escaped(data: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+
+    for (i, c) in data.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == separator {
+            parts.push(&data[start..i]);
+            start = i + c.len_utf8();
+        }
+    }
+    parts.push(&data[start..]);
+    parts
+}
+
+/// Remove backslash escapes (`\;` -> `;`, `\\` -> `\`).
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(other) = chars.next() {
+                result.push(other);
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(PayloadType::detect("WIFI:S:x;;"), PayloadType::Wifi);
+        assert_eq!(PayloadType::detect("wifi:S:x;;"), PayloadType::Wifi);
+        assert_eq!(
+            PayloadType::detect("BEGIN:VCARD\nEND:VCARD"),
+            PayloadType::VCard
+        );
+        assert_eq!(PayloadType::detect("MECARD:N:Doe;;"), PayloadType::MeCard);
+        assert_eq!(PayloadType::detect("BCD\n002\n1\nSCT"), PayloadType::Epc);
+        assert_eq!(PayloadType::detect("otpauth://totp/x"), PayloadType::Otp);
+        assert_eq!(PayloadType::detect("geo:1,2"), PayloadType::Geo);
+        assert_eq!(PayloadType::detect("mailto:a@b.c"), PayloadType::Email);
+        assert_eq!(PayloadType::detect("00020101021229"), PayloadType::Emv);
+        assert_eq!(PayloadType::detect("https://example.com"), PayloadType::Url);
+        assert_eq!(PayloadType::detect("hello"), PayloadType::Text);
+    }
+
+    #[test]
+    fn test_parse_fallback_text() {
+        let payload = Payload::parse("just some text").unwrap();
+        assert_eq!(payload, Payload::Text("just some text".to_string()));
+        assert_eq!(payload.payload_type(), PayloadType::Text);
+    }
+
+    #[test]
+    fn test_split_escaped() {
+        assert_eq!(split_escaped(r"a\;b;c", ';'), vec![r"a\;b", "c"]);
+        assert_eq!(unescape(r"a\;b\\c"), "a;b\\c");
+        // `\n` is not a WIFI or MECARD escape.
+        assert_eq!(unescape(r"a\nb"), "anb");
+    }
+}
@@ -0,0 +1,233 @@
+//! `otpauth://` one-time password provisioning URIs.
+
+use super::uri::{parse_query, percent_decode};
+use crate::error::{PayloadError, Result};
+
+const PAYLOAD: &str = "otpauth";
+
+/// OTP generator kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum OtpKind {
+    /// Time-based OTP with the step in seconds.
+    Totp {
+        /// Time step in seconds.
+        period: u32,
+    },
+    /// Counter-based OTP with the initial counter.
+    Hotp {
+        /// Initial counter value.
+        counter: u64,
+    },
+}
+
+/// HMAC algorithm used to derive codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OtpAlgorithm {
+    /// HMAC-SHA1 (default).
+    #[default]
+    Sha1,
+    /// HMAC-SHA256.
+    Sha256,
+    /// HMAC-SHA512.
+    Sha512,
+}
+
+/// OTP provisioning data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OtpAuth {
+    /// TOTP or HOTP with its kind-specific parameter.
+    pub kind: OtpKind,
+    /// Account name from the label.
+    pub account: String,
+    /// Issuer from the `issuer` parameter or the label prefix.
+    pub issuer: Option<String>,
+    /// Base32 shared secret, uppercased and without padding.
+    pub secret: String,
+    /// HMAC algorithm.
+    pub algorithm: OtpAlgorithm,
+    /// Number of digits (6 to 8).
+    pub digits: u8,
+}
+
+impl OtpAuth {
+    /// Parse an `otpauth://totp/Issuer:account?secret=...` URI.
+    pub fn parse(data: &str) -> Result<Self> {
+        let rest = data
+            .get(..10)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("otpauth://"))
+            .map(|_| &data[10..])
+            .ok_or(PayloadError::UnknownFormat)?;
+
+        let (kind, rest) = rest
+            .split_once('/')
+            .ok_or_else(|| PayloadError::Malformed {
+                payload: PAYLOAD,
+                reason: "missing `/label` after the OTP type".to_string(),
+            })?;
+        let (label, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+        let label = percent_decode(label, false, PAYLOAD, "label")?;
+        let (label_issuer, account) = match label.split_once(':') {
+            Some((issuer, account)) => (Some(issuer.trim().to_string()), account.trim()),
+            None => (None, label.trim()),
+        };
+        if account.is_empty() {
+            return Err(PayloadError::MissingField {
+                payload: PAYLOAD,
+                field: "label",
+            }
+            .into());
+        }
+
+        let mut secret = None;
+        let mut issuer = None;
+        let mut algorithm = OtpAlgorithm::default();
+        let mut digits = 6;
+        let mut period = 30;
+        let mut counter = None;
+
+        for (key, value) in parse_query(query, true, PAYLOAD)? {
+            match key.as_str() {
+                "secret" => secret = Some(value),
+                "issuer" => issuer = Some(value),
+                "algorithm" => {
+                    algorithm = match value.to_ascii_uppercase().as_str() {
+                        "SHA1" => OtpAlgorithm::Sha1,
+                        "SHA256" => OtpAlgorithm::Sha256,
+                        "SHA512" => OtpAlgorithm::Sha512,
+                        _ => {
+                            return Err(invalid(
+                                "algorithm",
+                                value,
+                                "expected SHA1, SHA256 or SHA512",
+                            ))
+                        }
+                    }
+                }
+                "digits" => {
+                    digits = value
+                        .parse()
+                        .ok()
+                        .filter(|d| (6..=8).contains(d))
+                        .ok_or_else(|| invalid("digits", value.clone(), "expected 6, 7 or 8"))?
+                }
+                "period" => {
+                    period = value.parse().ok().filter(|p| *p > 0).ok_or_else(|| {
+                        invalid(
+                            "period",
+                            value.clone(),
+                            "expected a positive number of seconds",
+                        )
+                    })?
+                }
+                "counter" => {
+                    counter = Some(value.parse().map_err(|_| {
+                        invalid("counter", value.clone(), "expected a non-negative integer")
+                    })?)
+                }
+                _ => {}
+            }
+        }
+
+        let kind = match kind.to_ascii_lowercase().as_str() {
+            "totp" => OtpKind::Totp { period },
+            "hotp" => OtpKind::Hotp {
+                counter: counter.ok_or(PayloadError::MissingField {
+                    payload: PAYLOAD,
+                    field: "counter",
+                })?,
+            },
+            _ => return Err(invalid("type", kind.to_string(), "expected totp or hotp")),
+        };
+
+        let secret = secret
+            .map(|s| s.trim_end_matches('=').to_ascii_uppercase())
+            .filter(|s| !s.is_empty())
+            .ok_or(PayloadError::MissingField {
+                payload: PAYLOAD,
+                field: "secret",
+            })?;
+        if !secret.chars().all(|c| matches!(c, 'A'..='Z' | '2'..='7')) {
+            return Err(invalid("secret", secret, "expected base32 (A-Z, 2-7)"));
+        }
+
+        if let (Some(param), Some(label)) = (&issuer, &label_issuer) {
+            if param != label {
+                return Err(PayloadError::Malformed {
+                    payload: PAYLOAD,
+                    reason: format!(
+                        "issuer parameter {:?} does not match label issuer {:?}",
+                        param, label
+                    ),
+                }
+                .into());
+            }
+        }
+
+        Ok(Self {
+            kind,
+            account: account.to_string(),
+            issuer: issuer.or(label_issuer),
+            secret,
+            algorithm,
+            digits,
+        })
+    }
+}
+
+fn invalid(field: &'static str, value: String, reason: &'static str) -> crate::error::QRError {
+    PayloadError::InvalidField {
+        payload: PAYLOAD,
+        field,
+        value,
+        reason,
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::QRError;
+
+    #[test]
+    fn test_parse_totp() {
+        let otp = OtpAuth::parse(
+            "otpauth://totp/ACME%20Co:john@example.com?secret=hxdmvjecjjwsrb3hwizr4ifugftmxboz&issuer=ACME%20Co&digits=8",
+        )
+        .unwrap();
+        assert_eq!(otp.kind, OtpKind::Totp { period: 30 });
+        assert_eq!(otp.account, "john@example.com");
+        assert_eq!(otp.issuer.as_deref(), Some("ACME Co"));
+        assert_eq!(otp.secret, "HXDMVJECJJWSRB3HWIZR4IFUGFTMXBOZ");
+        assert_eq!(otp.digits, 8);
+    }
+
+    #[test]
+    fn test_hotp_requires_counter() {
+        assert!(matches!(
+            OtpAuth::parse("otpauth://hotp/x?secret=JBSWY3DP"),
+            Err(QRError::InvalidPayload(PayloadError::MissingField {
+                field: "counter",
+                ..
+            }))
+        ));
+        let otp = OtpAuth::parse("otpauth://hotp/x?secret=JBSWY3DP&counter=7").unwrap();
+        assert_eq!(otp.kind, OtpKind::Hotp { counter: 7 });
+    }
+
+    #[test]
+    fn test_invalid_secret() {
+        assert!(matches!(
+            OtpAuth::parse("otpauth://totp/x?secret=not-base32"),
+            Err(QRError::InvalidPayload(PayloadError::InvalidField {
+                field: "secret",
+                ..
+            }))
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+//! Minimal URI helpers shared by the URI-based payload parsers.
+
+use crate::error::PayloadError;
+
+/// Decode `%XX` escapes. `+` is decoded as a space when `plus_as_space` is set.
+pub(crate) fn percent_decode(
+    value: &str,
+    plus_as_space: bool,
+    payload: &'static str,
+    field: &'static str,
+) -> Result<String, PayloadError> {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let hex = bytes
+                    .get(i + 1..i + 3)
+                    .and_then(|h| std::str::from_utf8(h).ok())
+                    .and_then(|h| u8::from_str_radix(h, 16).ok())
+                    .ok_or_else(|| PayloadError::InvalidField {
+                        payload,
+                        field,
+                        value: value.to_string(),
+                        reason: "invalid percent-encoding",
+                    })?;
+                out.push(hex);
+                i += 3;
+            }
+            b'+' if plus_as_space => {
+                out.push(b' ');
+                i += 1;
+            }
+            b => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8(out).map_err(|_| PayloadError::InvalidField {
+        payload,
+        field,
+        value: value.to_string(),
+        reason: "percent-decoded value is not valid UTF-8",
+    })
+}
+
+/// Split a query string into decoded `(key, value)` pairs.
+///
+/// Keys are lowercased since every format handled here treats them
+/// case-insensitively.
+pub(crate) fn parse_query(
+    query: &str,
+    plus_as_space: bool,
+    payload: &'static str,
+) -> Result<Vec<(String, String)>, PayloadError> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let key = percent_decode(key, plus_as_space, payload, "query")?.to_ascii_lowercase();
+            let value = percent_decode(value, plus_as_space, payload, "query")?;
+            Ok((key, value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("a%20b+c", true, "t", "f").unwrap(), "a b c");
+        assert_eq!(percent_decode("a+b", false, "t", "f").unwrap(), "a+b");
+        assert!(percent_decode("%2", false, "t", "f").is_err());
+    }
+
+    #[test]
+    fn test_parse_query() {
+        let pairs = parse_query("Secret=ABC&issuer=Acme%20Co", true, "t").unwrap();
+        assert_eq!(pairs[0], ("secret".to_string(), "ABC".to_string()));
+        assert_eq!(pairs[1], ("issuer".to_string(), "Acme Co".to_string()));
+    }
+}
@@ -0,0 +1,165 @@
+//! `WIFI:` network credential payloads.
+
+use super::{split_escaped, unescape};
+use crate::error::{PayloadError, Result};
+
+const PAYLOAD: &str = "WIFI";
+
+/// Wi-Fi authentication type (`T:` field).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum WifiSecurity {
+    /// Open network (`nopass` or no `T:` field).
+    #[default]
+    Open,
+    /// WEP.
+    Wep,
+    /// WPA/WPA2 personal.
+    Wpa,
+    /// WPA3 personal (`SAE`).
+    Wpa3,
+}
+
+/// Wi-Fi network credentials.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WifiNetwork {
+    /// Network name (`S:` field).
+    pub ssid: String,
+    /// Password (`P:` field).
+    pub password: Option<String>,
+    /// Authentication type (`T:` field).
+    pub security: WifiSecurity,
+    /// Whether the network is hidden (`H:` field).
+    pub hidden: bool,
+}
+
+impl WifiNetwork {
+    /// Parse a `WIFI:T:WPA;S:name;P:pass;;` string.
+    pub fn parse(data: &str) -> Result<Self> {
+        let body = data
+            .get(..5)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("WIFI:"))
+            .map(|_| &data[5..])
+            .ok_or(PayloadError::UnknownFormat)?;
+
+        let mut ssid = None;
+        let mut network = WifiNetwork::default();
+
+        for field in split_escaped(body, ';') {
+            if field.is_empty() {
+                continue;
+            }
+            let (key, value) = field
+                .split_once(':')
+                .ok_or_else(|| PayloadError::Malformed {
+                    payload: PAYLOAD,
+                    reason: format!("field {:?} has no `key:value` separator", field),
+                })?;
+            let value = unescape(value);
+
+            match key.to_ascii_uppercase().as_str() {
+                "S" => ssid = Some(value),
+                "P" => network.password = Some(value).filter(|p| !p.is_empty()),
+                "T" => network.security = parse_security(&value)?,
+                "H" => {
+                    network.hidden = match value.to_ascii_lowercase().as_str() {
+                        "true" | "1" => true,
+                        "false" | "0" | "" => false,
+                        _ => {
+                            return Err(PayloadError::InvalidField {
+                                payload: PAYLOAD,
+                                field: "H",
+                                value,
+                                reason: "expected `true` or `false`",
+                            }
+                            .into())
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        network.ssid = ssid
+            .filter(|s| !s.is_empty())
+            .ok_or(PayloadError::MissingField {
+                payload: PAYLOAD,
+                field: "S",
+            })?;
+
+        if network.security != WifiSecurity::Open && network.password.is_none() {
+            return Err(PayloadError::MissingField {
+                payload: PAYLOAD,
+                field: "P",
+            }
+            .into());
+        }
+
+        Ok(network)
+    }
+}
+
+fn parse_security(value: &str) -> Result<WifiSecurity> {
+    match value.to_ascii_uppercase().as_str() {
+        "" | "NOPASS" => Ok(WifiSecurity::Open),
+        "WEP" => Ok(WifiSecurity::Wep),
+        "WPA" | "WPA2" => Ok(WifiSecurity::Wpa),
+        "SAE" | "WPA3" => Ok(WifiSecurity::Wpa3),
+        _ => Err(PayloadError::InvalidField {
+            payload: PAYLOAD,
+            field: "T",
+            value: value.to_string(),
+            reason: "unknown authentication type",
+        }
+        .into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::QRError;
+
+    #[test]
+    fn test_parse_wpa() {
+        let network = WifiNetwork::parse(r"WIFI:T:WPA;S:My\;Net;P:p\:ss;H:true;;").unwrap();
+        assert_eq!(network.ssid, "My;Net");
+        assert_eq!(network.password.as_deref(), Some("p:ss"));
+        assert_eq!(network.security, WifiSecurity::Wpa);
+        assert!(network.hidden);
+    }
+
+    #[test]
+    fn test_parse_open() {
+        let network = WifiNetwork::parse("WIFI:S:Guest;T:nopass;;").unwrap();
+        assert_eq!(network.security, WifiSecurity::Open);
+        assert_eq!(network.password, None);
+    }
+
+    #[test]
+    fn test_missing_fields() {
+        assert!(matches!(
+            WifiNetwork::parse("WIFI:T:WPA;P:x;;"),
+            Err(QRError::InvalidPayload(PayloadError::MissingField {
+                field: "S",
+                ..
+            }))
+        ));
+        assert!(matches!(
+            WifiNetwork::parse("WIFI:T:WPA;S:x;;"),
+            Err(QRError::InvalidPayload(PayloadError::MissingField {
+                field: "P",
+                ..
+            }))
+        ));
+        assert!(matches!(
+            WifiNetwork::parse("WIFI:T:FOO;S:x;P:y;;"),
+            Err(QRError::InvalidPayload(PayloadError::InvalidField {
+                field: "T",
+                ..
+            }))
+        ));
+    }
+}
@@ -6,6 +6,9 @@
 
 use std::collections::HashMap;
 
+use crate::config::Color;
+use crate::error::Result;
+
 /// Position for specifying where decorations should be placed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -32,7 +35,8 @@ pub enum DecorationType {
 pub struct BorderOptions {
     /// Border thickness in pixels
     pub thickness: f64,
-    /// Border color (hex string like "#000000")
+    /// Border color as a CSS color string (e.g. "#000000", "navy" or
+    /// "rgb(0 0 0 / 50%)")
     pub color: String,
     /// Optional dash array for dashed borders (e.g., "5,5")
     pub dasharray: Option<String>,
@@ -58,6 +62,11 @@ impl BorderOptions {
         }
     }
 
+    /// Parse the border color.
+    pub fn parse_color(&self) -> Result<Color> {
+        self.color.parse()
+    }
+
     /// Set dash array for dashed border.
     pub fn with_dasharray(mut self, dasharray: impl Into<String>) -> Self {
         self.dasharray = Some(dasharray.into());
@@ -154,6 +163,19 @@ impl QRBorderOptions {
         self
     }
 
+    /// Check that every border color parses.
+    pub fn validate(&self) -> Result<()> {
+        let borders = [
+            Some(&self.border),
+            self.border_inner.as_ref(),
+            self.border_outer.as_ref(),
+        ];
+        for border in borders.into_iter().flatten() {
+            border.parse_color()?;
+        }
+        Ok(())
+    }
+
     /// Add a decoration at the specified position.
     pub fn with_decoration(mut self, position: Position, decoration: BorderDecoration) -> Self {
         self.decorations.insert(position, decoration);
@@ -191,6 +213,12 @@ impl BorderPlugin {
         Self { options }
     }
 
+    /// Create a new border plugin, failing if a border color doesn't parse.
+    pub fn try_new(options: QRBorderOptions) -> Result<Self> {
+        options.validate()?;
+        Ok(Self::new(options))
+    }
+
     /// Apply the border to an SVG string.
     /// Returns the modified SVG with borders and decorations.
     pub fn apply(&self, svg: &str, width: u32, height: u32) -> String {
@@ -209,14 +237,10 @@ impl BorderPlugin {
             let mut inner_attrs = self.generate_rect_attributes(width, height, inner);
 
             // Adjust inner border position and size
-            inner_attrs.x =
-                inner_attrs.x - inner.thickness + self.options.border.thickness;
-            inner_attrs.y =
-                inner_attrs.y - inner.thickness + self.options.border.thickness;
-            inner_attrs.width =
-                inner_attrs.width + 2.0 * (inner.thickness - self.options.border.thickness);
-            inner_attrs.height =
-                inner_attrs.height + 2.0 * (inner.thickness - self.options.border.thickness);
+            inner_attrs.x -= inner.thickness - self.options.border.thickness;
+            inner_attrs.y -= inner.thickness - self.options.border.thickness;
+            inner_attrs.width += 2.0 * (inner.thickness - self.options.border.thickness);
+            inner_attrs.height += 2.0 * (inner.thickness - self.options.border.thickness);
             inner_attrs.rx = (inner_attrs.rx + inner.thickness - self.options.border.thickness)
                 .max(0.0);
 
@@ -270,7 +294,7 @@ impl BorderPlugin {
             y: (height - size + options.thickness) / 2.0,
             width: size - options.thickness,
             height: size - options.thickness,
-            stroke: options.color.clone(),
+            stroke: stroke_color(&options.color),
             stroke_width: options.thickness,
             stroke_dasharray: options.dasharray.clone().unwrap_or_default(),
             rx,
@@ -493,6 +517,17 @@ struct RectAttributes {
     rx: f64,
 }
 
+/// A border color normalized to hex, or `rgba()` if translucent, so that
+/// SVG consumers without CSS Color 4 support draw it; unparseable colors
+/// are passed through.
+fn stroke_color(color: &str) -> String {
+    match color.parse::<Color>() {
+        Ok(parsed) if parsed.a == 255 => parsed.to_hex(),
+        Ok(parsed) => parsed.to_rgba_string(),
+        Err(_) => color.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -535,6 +570,20 @@ mod tests {
         assert!(result.contains("stroke-width=\"10\""));
     }
 
+    #[test]
+    fn test_border_color_validation() {
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="300" height="300"></svg>"#;
+        let options = QRBorderOptions::new(10.0, "hsl(0 100% 50% / 0.5)")
+            .with_outer_border(BorderOptions::new(4.0, "navy"));
+        let result = BorderPlugin::try_new(options).unwrap().apply(svg, 300, 300);
+        assert!(result.contains("stroke=\"rgba(255, 0, 0, 0.502)\""));
+        assert!(result.contains("stroke=\"#000080\""));
+
+        let options =
+            QRBorderOptions::new(10.0, "#000").with_inner_border(BorderOptions::new(5.0, "blak"));
+        assert!(BorderPlugin::try_new(options).is_err());
+    }
+
     #[test]
     fn test_border_with_text_decoration() {
         let svg = r#"<?xml version="1.0"?>
@@ -0,0 +1,11 @@
+//! Named style presets.
+//!
+//! A [`Theme`] is a complete look (dots, finder patterns and background)
+//! under a name. Built-in themes and any registered with [`register`] can be
+//! applied with [`QRCodeStylingBuilder::preset`](crate::QRCodeStylingBuilder::preset).
+
+mod registry;
+mod theme;
+
+pub use registry::{get, names, register, PresetRegistry};
+pub use theme::Theme;
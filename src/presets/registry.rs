@@ -0,0 +1,184 @@
+//! Theme registry with the built-in presets.
+
+use std::f64::consts::PI;
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+use super::Theme;
+use crate::config::{
+    BackgroundOptions, Color, ColorStop, CornersDotOptions, CornersSquareOptions, DotsOptions,
+    Gradient,
+};
+use crate::types::{CornerDotType, CornerSquareType, DotType};
+
+/// A set of themes looked up by name.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PresetRegistry {
+    themes: Vec<Theme>,
+}
+
+impl PresetRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a registry holding the built-in themes: "classic",
+    /// "rounded-navy", "instagram-gradient", "minimal-dots" and "classy".
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        for theme in builtins() {
+            registry.register(theme);
+        }
+        registry
+    }
+
+    /// Add a theme, replacing any with the same name.
+    pub fn register(&mut self, theme: Theme) {
+        match self.themes.iter_mut().find(|t| t.name == theme.name) {
+            Some(existing) => *existing = theme,
+            None => self.themes.push(theme),
+        }
+    }
+
+    /// The theme registered under `name`.
+    pub fn get(&self, name: &str) -> Option<&Theme> {
+        self.themes.iter().find(|t| t.name == name)
+    }
+
+    /// Names of all themes, in registration order.
+    pub fn names(&self) -> Vec<String> {
+        self.themes.iter().map(|t| t.name.clone()).collect()
+    }
+}
+
+/// Themes available to [`QRCodeStylingBuilder::preset`](crate::QRCodeStylingBuilder::preset).
+fn global() -> &'static RwLock<PresetRegistry> {
+    static REGISTRY: OnceLock<RwLock<PresetRegistry>> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(PresetRegistry::with_builtins()))
+}
+
+/// Register a theme for use by name, replacing any with the same name,
+/// built-ins included.
+pub fn register(theme: Theme) {
+    global()
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .register(theme);
+}
+
+/// The registered theme named `name`.
+pub fn get(name: &str) -> Option<Theme> {
+    global()
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(name)
+        .cloned()
+}
+
+/// Names of all registered themes.
+pub fn names() -> Vec<String> {
+    global()
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .names()
+}
+
+/// Finder corner radius of the built-in rounded eyes; larger radii move the
+/// eye corners too far for the detector to fit the grid.
+const EYE_RADIUS: f64 = 0.3;
+
+fn builtins() -> Vec<Theme> {
+    let navy = Color::rgb(0x1B, 0x2A, 0x4E);
+    let charcoal = Color::rgb(0x2C, 0x3E, 0x50);
+    let ink = Color::rgb(0x22, 0x22, 0x22);
+    let instagram = Gradient::linear_rotated(
+        PI / 4.0,
+        vec![
+            ColorStop::new(0.0, Color::rgb(0xE1, 0x5A, 0x1E)),
+            ColorStop::new(0.35, Color::rgb(0xD6, 0x29, 0x76)),
+            ColorStop::new(0.7, Color::rgb(0x96, 0x2F, 0xBF)),
+            ColorStop::new(1.0, Color::rgb(0x4F, 0x5B, 0xD5)),
+        ],
+    );
+    let white = BackgroundOptions::new(Color::WHITE);
+    let rounded_eye = CornersSquareOptions::new(CornerSquareType::Rounded).with_radius(EYE_RADIUS);
+
+    vec![
+        Theme::new("classic")
+            .with_corners_dot_options(CornersDotOptions::new(CornerDotType::Square))
+            .with_background_options(white.clone()),
+        Theme::new("rounded-navy")
+            .with_dots_options(DotsOptions::new(DotType::Rounded).with_color(navy))
+            .with_corners_square_options(rounded_eye.clone().with_color(navy))
+            .with_corners_dot_options(CornersDotOptions::new(CornerDotType::Dot).with_color(navy))
+            .with_background_options(white.clone()),
+        Theme::new("instagram-gradient")
+            .with_dots_options(DotsOptions::new(DotType::Rounded).with_gradient(instagram.clone()))
+            .with_corners_square_options(rounded_eye.with_gradient(instagram))
+            .with_corners_dot_options(
+                CornersDotOptions::new(CornerDotType::Dot).with_color(Color::rgb(0xC1, 0x35, 0x84)),
+            )
+            .with_background_options(white.clone()),
+        Theme::new("minimal-dots")
+            .with_dots_options(DotsOptions::new(DotType::Dots).with_color(ink))
+            .with_corners_square_options(
+                CornersSquareOptions::new(CornerSquareType::DotsRing).with_color(ink),
+            )
+            .with_corners_dot_options(CornersDotOptions::new(CornerDotType::Dot).with_color(ink))
+            .with_background_options(white.clone()),
+        Theme::new("classy")
+            .with_dots_options(DotsOptions::new(DotType::ClassyRounded).with_color(charcoal))
+            .with_corners_square_options(
+                CornersSquareOptions::new(CornerSquareType::Leaf)
+                    .with_radius(EYE_RADIUS)
+                    .with_color(charcoal),
+            )
+            .with_corners_dot_options(
+                CornersDotOptions::new(CornerDotType::Dot).with_color(charcoal),
+            )
+            .with_background_options(white),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QRCodeStyling;
+
+    #[test]
+    fn test_builtins_scan() {
+        for name in PresetRegistry::with_builtins().names() {
+            let qr = QRCodeStyling::builder()
+                .data("https://example.com/presets")
+                .preset(&name)
+                .build()
+                .unwrap();
+            let report = qr.verify().unwrap();
+            assert!(report.is_ok(), "{}: {:?}", name, report.error);
+            assert!(!report.grid_sampled, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_register_replaces_by_name() {
+        let mut registry = PresetRegistry::with_builtins();
+        let count = registry.names().len();
+        let red = DotsOptions::default().with_color(Color::rgb(200, 0, 0));
+        registry.register(Theme::new("classic").with_dots_options(red.clone()));
+        registry.register(Theme::new("brand"));
+        assert_eq!(registry.names().len(), count + 1);
+        assert_eq!(registry.get("classic").unwrap().dots_options, red);
+    }
+
+    #[test]
+    fn test_registration() {
+        let mut registry = PresetRegistry::with_builtins();
+        let theme = Theme::new("brand").with_dots_options(DotsOptions::new(DotType::Dots));
+        registry.register(theme.clone());
+        assert_eq!(registry.names().last(), Some(&"brand".to_string()));
+        assert_eq!(registry.get("brand"), Some(&theme));
+        assert!(registry.get("rounded-navy").is_some());
+        assert!(PresetRegistry::new().get("rounded-navy").is_none());
+    }
+}
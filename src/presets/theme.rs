@@ -0,0 +1,75 @@
+//! A complete named style.
+
+use crate::config::{
+    BackgroundOptions, CornersDotOptions, CornersSquareOptions, DotsOptions, QRCodeStylingOptions,
+};
+
+/// Styling for the dots, finder patterns and background under one name.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Theme {
+    /// Name the theme is registered under.
+    pub name: String,
+    /// Dot styling.
+    pub dots_options: DotsOptions,
+    /// Corner square styling.
+    pub corners_square_options: CornersSquareOptions,
+    /// Corner dot styling.
+    pub corners_dot_options: CornersDotOptions,
+    /// Background styling.
+    pub background_options: BackgroundOptions,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new("default")
+    }
+}
+
+impl Theme {
+    /// Create a theme with default styling.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            dots_options: DotsOptions::default(),
+            corners_square_options: CornersSquareOptions::default(),
+            corners_dot_options: CornersDotOptions::default(),
+            background_options: BackgroundOptions::default(),
+        }
+    }
+
+    /// Capture the styling of existing options as a theme.
+    pub fn from_options(name: impl Into<String>, options: &QRCodeStylingOptions) -> Self {
+        Self {
+            name: name.into(),
+            dots_options: options.dots_options.clone(),
+            corners_square_options: options.corners_square_options.clone(),
+            corners_dot_options: options.corners_dot_options.clone(),
+            background_options: options.background_options.clone(),
+        }
+    }
+
+    /// Set the dot styling.
+    pub fn with_dots_options(mut self, options: DotsOptions) -> Self {
+        self.dots_options = options;
+        self
+    }
+
+    /// Set the corner square styling.
+    pub fn with_corners_square_options(mut self, options: CornersSquareOptions) -> Self {
+        self.corners_square_options = options;
+        self
+    }
+
+    /// Set the corner dot styling.
+    pub fn with_corners_dot_options(mut self, options: CornersDotOptions) -> Self {
+        self.corners_dot_options = options;
+        self
+    }
+
+    /// Set the background styling.
+    pub fn with_background_options(mut self, options: BackgroundOptions) -> Self {
+        self.background_options = options;
+        self
+    }
+}
@@ -7,3 +7,4 @@ mod pdf_renderer;
 pub use svg_renderer::SvgRenderer;
 pub use raster_renderer::RasterRenderer;
 pub use pdf_renderer::PdfRenderer;
+pub(crate) use pdf_renderer::EFFECT_RASTER_SCALE;
@@ -1,33 +1,49 @@
 //! PDF renderer for QR codes using SVG to PDF vector conversion.
+//!
+//! PDF has no filter effects, so svg2pdf rasterizes each filtered group on
+//! its own; the renderer keeps effects in separate groups, leaving the
+//! code itself vector.
 
 use crate::error::{QRError, Result};
 use svg2pdf::usvg;
 
+/// Pixels per unit for rasterized effects, so inner shadow edges stay
+/// close to the vector shapes.
+pub(crate) const EFFECT_RASTER_SCALE: f32 = 3.0;
+
 /// PDF renderer for converting SVG to PDF format (vector).
 pub struct PdfRenderer;
 
 impl PdfRenderer {
     /// Render SVG string directly to PDF format (preserves vector quality).
     pub fn render_from_svg(svg: &str, _width: u32, _height: u32) -> Result<Vec<u8>> {
+        Self::render_with_raster_scale(svg, svg2pdf::ConversionOptions::default().raster_scale)
+    }
+
+    /// Render SVG to PDF, rasterizing filters at `raster_scale` pixels per
+    /// unit.
+    pub fn render_with_raster_scale(svg: &str, raster_scale: f32) -> Result<Vec<u8>> {
         // Create font database and load system fonts
         let mut fontdb = usvg::fontdb::Database::new();
         fontdb.load_system_fonts();
         let fontdb = std::sync::Arc::new(fontdb);
 
         // Create options with the font database
-        let mut options = usvg::Options::default();
-        options.fontdb = fontdb;
+        let options = usvg::Options {
+            fontdb,
+            ..Default::default()
+        };
 
         // Parse SVG using usvg with font database (text will be converted to paths)
         let tree = usvg::Tree::from_str(svg, &options)
             .map_err(|e| QRError::SvgError(format!("Failed to parse SVG: {}", e)))?;
 
         // Convert to PDF using svg2pdf
-        let pdf = svg2pdf::to_pdf(
-            &tree,
-            svg2pdf::ConversionOptions::default(),
-            svg2pdf::PageOptions::default(),
-        )
+        let conversion = svg2pdf::ConversionOptions {
+            raster_scale,
+            ..Default::default()
+        };
+        let pdf = svg2pdf::to_pdf(&tree, conversion, svg2pdf::PageOptions::default())
         .map_err(|e| QRError::ImageEncodeError(format!("PDF conversion error: {:?}", e)))?;
 
         Ok(pdf)
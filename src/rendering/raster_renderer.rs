@@ -21,7 +21,7 @@ impl RasterRenderer {
     }
 
     /// Parse SVG and render to image buffer using resvg.
-    fn svg_to_image(svg: &str, width: u32, height: u32) -> Result<DynamicImage> {
+    pub(crate) fn svg_to_image(svg: &str, width: u32, height: u32) -> Result<DynamicImage> {
         // Parse SVG using usvg
         let tree = Tree::from_str(svg, &Options::default())
             .map_err(|e| QRError::SvgError(e.to_string()))?;
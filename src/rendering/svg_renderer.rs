@@ -1,12 +1,22 @@
 //! SVG renderer for QR codes.
 
+use std::collections::HashMap;
 use std::f64::consts::PI;
 
-use crate::config::{Color, Gradient, QRCodeStylingOptions};
-use crate::core::QRMatrix;
+use crate::config::{
+    BackgroundImage, Color, CornerArtwork, CornersDotOptions, CornersSquareOptions, DotsOptions,
+    Effect, Gradient, ModuleInfo, OutlineOptions, PatternFill, PatternKind, QRCodeStylingOptions,
+};
+use crate::core::{Halftone, ModuleKind, QRLayout, QRMatrix};
 use crate::error::Result;
+use crate::figures::dot::region_path;
+use crate::figures::traits::{prepend_transform, rotate_transform, svg_path};
 use crate::figures::{QRCornerDot, QRCornerSquare, QRDot};
-use crate::types::{CornerSquareType, GradientType, ShapeType};
+use crate::types::{
+    CornerDotType, CornerOrientation, CornerSquareType, DotType, FinderCorner, GradientType,
+    GradientUnits, ImageFit, ShapeType, SpreadMethod,
+};
+use crate::utils::SeededRng;
 
 /// SVG renderer for QR codes.
 pub struct SvgRenderer {
@@ -36,6 +46,19 @@ const DOT_MASK: [[u8; 7]; 7] = [
     [0, 0, 0, 0, 0, 0, 0],
 ];
 
+/// Number of wedges a conic gradient is drawn with.
+const CONIC_STEPS: usize = 180;
+
+/// Modules drawn together with one set of dots options.
+struct DotGroup<'a> {
+    /// Structural group, or `None` for modules styled by the dots options.
+    key: Option<ModuleKind>,
+    /// Styling for the group.
+    options: &'a DotsOptions,
+    /// Prefix of the group's clip path and fill ids.
+    name: &'static str,
+}
+
 static INSTANCE_COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
 
 impl SvgRenderer {
@@ -50,51 +73,68 @@ impl SvgRenderer {
 
     /// Render the QR code as SVG string.
     pub fn render(&self, matrix: &QRMatrix) -> Result<String> {
+        // Options edited after building have not been validated yet.
+        self.options.validate()?;
         let count = matrix.module_count();
-        let min_size = self.options.width.min(self.options.height) - self.options.margin * 2;
-        let real_qr_size = if self.options.shape == ShapeType::Circle {
-            min_size as f64 / 2.0_f64.sqrt()
-        } else {
-            min_size as f64
-        };
-        let dot_size = self.round_size(real_qr_size / count as f64);
+        let dot_size = self.dot_size(count);
 
-        // Calculate image hiding area if there's an image
-        let (hide_x_dots, hide_y_dots) = if self.options.image.is_some() {
-            self.calculate_image_hide_area(count, dot_size)
-        } else {
-            (0, 0)
-        };
+        // Size the image hiding area against the error correction capacity
+        let coverage = crate::analysis::coverage(&self.options, matrix)?;
+        let hide_dots = coverage.hide_dots;
+        let (hide_x_dots, hide_y_dots) = (hide_dots, hide_dots);
 
         let mut svg_content = String::with_capacity(10000);
         let mut defs_content = String::new();
         let mut elements_content = String::new();
 
         // Draw background
-        let (bg_defs, bg_elements) = self.render_background();
+        let (bg_defs, bg_elements) = self.render_background(count);
         defs_content.push_str(&bg_defs);
         elements_content.push_str(&bg_elements);
 
-        // Draw dots
-        let (dots_defs, dots_elements) = self.render_dots(
-            matrix,
-            count,
-            dot_size,
-            hide_x_dots,
-            hide_y_dots,
-        );
+        // Draw dots, or the halftone picture with its module centers
+        let (dots_defs, dots_elements) = match &self.options.halftone {
+            Some(halftone) => {
+                let plan = Halftone::new(halftone, matrix, &coverage)?;
+                self.render_halftone(matrix, &plan, count, dot_size, hide_x_dots, hide_y_dots)
+            }
+            None => self.render_dot_groups(matrix, count, dot_size, hide_x_dots, hide_y_dots),
+        };
         defs_content.push_str(&dots_defs);
-        elements_content.push_str(&dots_elements);
+
+        // Draw whole alignment patterns
+        let (alignment_defs, alignment_elements) =
+            self.render_alignment(matrix, count, dot_size, hide_x_dots, hide_y_dots);
+        defs_content.push_str(&alignment_defs);
+
+        let effects = &self.options.effects;
+        let dots_layer = format!("{}{}", dots_elements, alignment_elements);
+        elements_content.push_str(&self.apply_effects(
+            "dots",
+            &effects.dots,
+            &dots_layer,
+            &mut defs_content,
+        ));
 
         // Draw corners
         let (corners_defs, corners_elements) = self.render_corners(count, dot_size);
         defs_content.push_str(&corners_defs);
-        elements_content.push_str(&corners_elements);
+        elements_content.push_str(&self.apply_effects(
+            "corners",
+            &effects.corners,
+            &corners_elements,
+            &mut defs_content,
+        ));
 
         // Draw image if present
         if let Some(ref image_data) = self.options.image {
             let image_svg = self.render_image(count, dot_size, hide_x_dots, hide_y_dots, image_data);
-            elements_content.push_str(&image_svg);
+            elements_content.push_str(&self.apply_effects(
+                "image",
+                &effects.image,
+                &image_svg,
+                &mut defs_content,
+            ));
         }
 
         // Build final SVG
@@ -124,7 +164,123 @@ impl SvgRenderer {
         Ok(svg_content)
     }
 
-    fn render_background(&self) -> (String, String) {
+    /// Surround a layer with its effects: shadows and glows in filtered
+    /// copies underneath, inner shadows on top. Each filter outputs only
+    /// the effect, so PDF output rasterizes nothing but the effects.
+    fn apply_effects(
+        &self,
+        layer: &str,
+        effects: &[Effect],
+        elements: &str,
+        defs: &mut String,
+    ) -> String {
+        if effects.is_empty() {
+            return elements.to_string();
+        }
+        // The layer is defined once and referenced by every copy, so large
+        // content such as an embedded logo is not repeated.
+        let layer_id = format!("layer-{}-{}", layer, self.instance_id);
+        defs.push_str(&format!("<g id=\"{}\">\n{}</g>\n", layer_id, elements));
+        let (mut below, mut above) = (String::new(), String::new());
+        for (i, effect) in effects.iter().enumerate() {
+            let id = format!("effect-{}-{}-{}", layer, i, self.instance_id);
+            defs.push_str(&self.effect_filter(&id, effect));
+            let copy = format!(
+                "<use href=\"#{0}\" xlink:href=\"#{0}\" filter=\"url(#{1})\"/>\n",
+                layer_id, id
+            );
+            match effect {
+                Effect::InnerShadow { .. } => above.push_str(&copy),
+                Effect::DropShadow { .. } | Effect::Glow { .. } => below.push_str(&copy),
+            }
+        }
+        format!(
+            "{0}<use href=\"#{1}\" xlink:href=\"#{1}\"/>\n{2}",
+            below, layer_id, above
+        )
+    }
+
+    /// SVG filter drawing `effect` for the shapes it is applied to.
+    fn effect_filter(&self, id: &str, effect: &Effect) -> String {
+        let flood = |color: &Color| {
+            format!(
+                r#"<feFlood flood-color="rgb({},{},{})" flood-opacity="{}"/>"#,
+                color.r,
+                color.g,
+                color.b,
+                color.a as f64 / 255.0
+            )
+        };
+        // Shadow of the shapes, blurred, offset and colored.
+        let outer = |dx: f64, dy: f64, blur: f64, color: &Color| {
+            format!(
+                r#"<feGaussianBlur in="SourceAlpha" stdDeviation="{}"/>
+<feOffset dx="{}" dy="{}" result="shadow"/>
+{}
+<feComposite in2="shadow" operator="in"/>"#,
+                blur / 2.0,
+                dx,
+                dy,
+                flood(color)
+            )
+        };
+        let primitives = match effect {
+            Effect::DropShadow {
+                dx,
+                dy,
+                blur,
+                color,
+            } => outer(*dx, *dy, *blur, color),
+            Effect::Glow { blur, color } => outer(0.0, 0.0, *blur, color),
+            // Blur the inverted shapes, then keep what falls inside them.
+            Effect::InnerShadow {
+                dx,
+                dy,
+                blur,
+                color,
+            } => format!(
+                r#"<feComponentTransfer in="SourceAlpha"><feFuncA type="table" tableValues="1 0"/></feComponentTransfer>
+<feGaussianBlur stdDeviation="{}"/>
+<feOffset dx="{}" dy="{}" result="shadow"/>
+{}
+<feComposite in2="shadow" operator="in"/>
+<feComposite in2="SourceAlpha" operator="in"/>"#,
+                blur / 2.0,
+                dx,
+                dy,
+                flood(color)
+            ),
+        };
+        format!(
+            r#"<filter id="{}" filterUnits="userSpaceOnUse" x="0" y="0" width="{}" height="{}" color-interpolation-filters="sRGB">
+{}
+</filter>
+"#,
+            id, self.options.width, self.options.height, primitives
+        )
+    }
+
+    /// Size of one module in SVG units.
+    pub(crate) fn dot_size(&self, count: usize) -> f64 {
+        let min_size = self.options.width.min(self.options.height) - self.options.margin * 2;
+        let real_qr_size = if self.options.shape == ShapeType::Circle {
+            min_size as f64 / 2.0_f64.sqrt()
+        } else {
+            min_size as f64
+        };
+        self.round_size(real_qr_size / count as f64)
+    }
+
+    /// Top-left corner of the module grid, in SVG units.
+    pub(crate) fn grid_origin(&self, count: usize) -> (f64, f64) {
+        let dot_size = self.dot_size(count);
+        (
+            self.round_size((self.options.width as f64 - count as f64 * dot_size) / 2.0),
+            self.round_size((self.options.height as f64 - count as f64 * dot_size) / 2.0),
+        )
+    }
+
+    fn render_background(&self, count: usize) -> (String, String) {
         let mut defs = String::new();
         let mut elements = String::new();
 
@@ -182,9 +338,164 @@ impl SvgRenderer {
             0, 0, self.options.width, self.options.height, fill, name
         ));
 
+        if let Some(image) = &bg.image {
+            let area = (x, y, width as f64, height as f64);
+            let (image_defs, image_elements) =
+                self.render_background_image(image, area, count, &name);
+            defs.push_str(&image_defs);
+            elements.push_str(&image_elements);
+        }
+
+        (defs, elements)
+    }
+
+    /// Draw the background image over `area`, filtered and faded, then the
+    /// readability panel one module out from the grid.
+    fn render_background_image(
+        &self,
+        image: &BackgroundImage,
+        (x, y, width, height): (f64, f64, f64, f64),
+        count: usize,
+        name: &str,
+    ) -> (String, String) {
+        let mut defs = String::new();
+        let url = data_url(&image.image);
+
+        let picture = match image.fit {
+            ImageFit::Tile => {
+                let (tile_width, tile_height) = image_size(&image.image).unwrap_or((width, height));
+                defs.push_str(&format!(
+                    r#"<pattern id="{name}-image" patternUnits="userSpaceOnUse" x="{}" y="{}" width="{}" height="{}">
+<image href="{url}" xlink:href="{url}" width="{}" height="{}" preserveAspectRatio="none"/>
+</pattern>
+"#,
+                    x, y, tile_width, tile_height, tile_width, tile_height
+                ));
+                format!(
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="url(#{}-image)""#,
+                    x, y, width, height, name
+                )
+            }
+            fit => {
+                let aspect = match fit {
+                    ImageFit::Contain => "xMidYMid meet",
+                    ImageFit::Stretch => "none",
+                    _ => "xMidYMid slice",
+                };
+                format!(
+                    r#"<image href="{url}" xlink:href="{url}" x="{}" y="{}" width="{}" height="{}" preserveAspectRatio="{}""#,
+                    x, y, width, height, aspect
+                )
+            }
+        };
+
+        let mut effects = String::new();
+        if image.desaturate > 0.0 {
+            effects.push_str(&format!(
+                "<feColorMatrix type=\"saturate\" values=\"{}\"/>\n",
+                1.0 - image.desaturate
+            ));
+        }
+        if image.blur > 0.0 {
+            effects.push_str(&format!(
+                "<feGaussianBlur stdDeviation=\"{}\"/>\n",
+                image.blur
+            ));
+        }
+        let filter = if effects.is_empty() {
+            String::new()
+        } else {
+            defs.push_str(&format!(
+                r#"<filter id="{}-filter" filterUnits="userSpaceOnUse" x="{}" y="{}" width="{}" height="{}" color-interpolation-filters="sRGB">
+{}</filter>
+"#,
+                name, x, y, width, height, effects
+            ));
+            format!(r#" filter="url(#{}-filter)""#, name)
+        };
+
+        let mut elements = format!(
+            r#"<g clip-path="url(#clip-path-{})"><g opacity="{}"{}>{}/></g>
+"#,
+            name, image.opacity, filter, picture
+        );
+
+        if let Some(overlay) = image.overlay {
+            let dot_size = self.dot_size(count);
+            let (grid_x, grid_y) = self.grid_origin(count);
+            let side = (count + 2) as f64 * dot_size;
+            let color = self.options.background_options.color.over(&Color::WHITE);
+            elements.push_str(&format!(
+                r#"<rect x="{}" y="{}" width="{}" height="{}" rx="{}" fill="{}" fill-opacity="{}"/>
+"#,
+                grid_x - dot_size,
+                grid_y - dot_size,
+                side,
+                side,
+                dot_size,
+                Color::rgb(color.r, color.g, color.b).to_hex(),
+                overlay
+            ));
+        }
+        elements.push_str("</g>\n");
+
+        (defs, elements)
+    }
+
+    /// Draw data modules with the dots options, and each structural group
+    /// that has its own style separately.
+    fn render_dot_groups(
+        &self,
+        matrix: &QRMatrix,
+        count: usize,
+        dot_size: f64,
+        hide_x_dots: usize,
+        hide_y_dots: usize,
+    ) -> (String, String) {
+        let structural = &self.options.structural_options;
+        let groups = [
+            (None, Some(&self.options.dots_options), "dot-color"),
+            (
+                Some(ModuleKind::Timing),
+                structural.timing.as_ref(),
+                "timing-color",
+            ),
+            (
+                Some(ModuleKind::Alignment),
+                structural.alignment.as_ref(),
+                "alignment-color",
+            ),
+            (
+                Some(ModuleKind::FormatInfo),
+                structural.format_info.as_ref(),
+                "format-color",
+            ),
+        ];
+
+        let (mut defs, mut elements) = (String::new(), String::new());
+        for (key, options, name) in groups {
+            let Some(options) = options else {
+                continue;
+            };
+            let group = DotGroup { key, options, name };
+            let (group_defs, group_elements) =
+                self.render_dots(matrix, count, dot_size, hide_x_dots, hide_y_dots, &group);
+            defs.push_str(&group_defs);
+            elements.push_str(&group_elements);
+        }
         (defs, elements)
     }
 
+    /// Structural group a module is drawn with, or `None` for the dots options.
+    fn module_group(&self, layout: &QRLayout, row: usize, col: usize) -> Option<ModuleKind> {
+        let kind = layout.kind(row, col);
+        self.options.structural_options.style_for(kind)?;
+        Some(match kind {
+            ModuleKind::VersionInfo | ModuleKind::DarkModule => ModuleKind::FormatInfo,
+            kind => kind,
+        })
+    }
+
     fn render_dots(
         &self,
         matrix: &QRMatrix,
@@ -192,6 +503,7 @@ impl SvgRenderer {
         dot_size: f64,
         hide_x_dots: usize,
         hide_y_dots: usize,
+        group: &DotGroup,
     ) -> (String, String) {
         let mut defs = String::new();
         let mut clip_path_elements = String::new();
@@ -199,51 +511,69 @@ impl SvgRenderer {
         let x_beginning = self.round_size((self.options.width as f64 - count as f64 * dot_size) / 2.0);
         let y_beginning = self.round_size((self.options.height as f64 - count as f64 * dot_size) / 2.0);
 
-        let dot_drawer = QRDot::new(self.options.dots_options.dot_type);
-        let name = format!("dot-color-{}", self.instance_id);
-
-        // Create dots clip path
-        for row in 0..count {
-            for col in 0..count {
-                // Apply filter
-                if !self.should_draw_dot(row, col, count, hide_x_dots, hide_y_dots) {
-                    continue;
-                }
+        let layout = matrix.layout();
+        let units = self.alignment_units(&layout, count, hide_x_dots, hide_y_dots);
+        let drawn = |row: usize, col: usize| -> bool {
+            self.should_draw_dot(row, col, count, hide_x_dots, hide_y_dots)
+                && matrix.is_dark(row, col)
+                && self.module_group(&layout, row, col) == group.key
+                && !in_alignment_unit(&units, row, col)
+        };
 
-                if !matrix.is_dark(row, col) {
-                    continue;
-                }
+        let dots_options = group.options;
+        let mut dot_drawer = QRDot::new(dots_options.dot_type)
+            .with_custom_drawer(dots_options.custom_drawer.clone());
+        // Liquid outlines span many modules, so they are never jittered.
+        if dots_options.dot_type != DotType::Liquid {
+            dot_drawer = dot_drawer.with_jitter(dots_options.jitter());
+        }
+        let name = format!("{}-{}", group.name, self.instance_id);
 
-                let x = x_beginning + col as f64 * dot_size;
-                let y = y_beginning + row as f64 * dot_size;
+        // Outlined square modules are outlined as whole regions, without
+        // the seams between touching modules.
+        let merged = dots_options.outline.is_some()
+            && dots_options.dot_type == DotType::Square
+            && dots_options.jitter().is_identity();
 
-                let neighbor_fn = |x_offset: i32, y_offset: i32| -> bool {
-                    let new_col = col as i32 + x_offset;
-                    let new_row = row as i32 + y_offset;
-                    if new_col < 0 || new_row < 0 || new_col >= count as i32 || new_row >= count as i32
-                    {
-                        return false;
-                    }
-                    if !self.should_draw_dot(
-                        new_row as usize,
-                        new_col as usize,
-                        count,
-                        hide_x_dots,
-                        hide_y_dots,
-                    ) {
-                        return false;
+        // Create dots clip path
+        if dots_options.dot_type == DotType::Liquid || merged {
+            // Liquid regions are traced as whole outlines, not per module.
+            let radius = if merged { 0.0 } else { dot_size / 2.0 };
+            let path = region_path(count, x_beginning, y_beginning, dot_size, radius, drawn);
+            clip_path_elements.push_str(&path);
+            clip_path_elements.push('\n');
+        } else {
+            for row in 0..count {
+                for col in 0..count {
+                    if !drawn(row, col) {
+                        continue;
                     }
-                    matrix.is_dark(new_row as usize, new_col as usize)
-                };
 
-                let svg = dot_drawer.draw(x, y, dot_size, Some(&neighbor_fn));
-                clip_path_elements.push_str(&svg);
-                clip_path_elements.push('\n');
+                    let x = x_beginning + col as f64 * dot_size;
+                    let y = y_beginning + row as f64 * dot_size;
+
+                    let neighbor_fn = |x_offset: i32, y_offset: i32| -> bool {
+                        let new_col = col as i32 + x_offset;
+                        let new_row = row as i32 + y_offset;
+                        if new_col < 0
+                            || new_row < 0
+                            || new_col >= count as i32
+                            || new_row >= count as i32
+                        {
+                            return false;
+                        }
+                        drawn(new_row as usize, new_col as usize)
+                    };
+
+                    let svg = dot_drawer.draw_module(row, col, x, y, dot_size, Some(&neighbor_fn));
+                    clip_path_elements.push_str(&svg);
+                    clip_path_elements.push('\n');
+                }
             }
         }
 
         // Handle circle shape with fake edge dots
-        if self.options.shape == ShapeType::Circle {
+        if self.options.shape == ShapeType::Circle && group.key.is_none() {
             let circle_dots = self.render_circle_edge_dots(matrix, count, dot_size, x_beginning, y_beginning, &dot_drawer);
             clip_path_elements.push_str(&circle_dots);
         }
@@ -255,29 +585,159 @@ impl SvgRenderer {
 "#,
             name, clip_path_elements
         ));
-
-        // Create color rect
-        let (grad_defs, fill) = self.create_color(
-            self.options.dots_options.gradient.as_ref(),
-            &self.options.dots_options.color,
-            0.0,
-            0.0,
-            0.0,
-            self.options.height as f64,
-            self.options.width as f64,
+        let (under, mask) = self.outline_layer(
             &name,
+            &clip_path_elements,
+            dots_options.outline.as_ref(),
+            dot_size,
+            (
+                0.0,
+                0.0,
+                self.options.width as f64,
+                self.options.height as f64,
+            ),
+            &mut defs,
         );
+
+        if dots_options.has_module_colors() {
+            let cells = self.render_module_colors(
+                matrix,
+                count,
+                dot_size,
+                x_beginning,
+                y_beginning,
+                dots_options,
+                group.key.is_none(),
+                &drawn,
+            );
+            let elements = format!(
+                r#"{}<g clip-path="url(#clip-path-{})"{}>
+{}</g>
+"#,
+                under, name, mask, cells
+            );
+            return (defs, elements);
+        }
+
+        // Create color rect
+        let side = count as f64 * dot_size;
+        let (grad_defs, fill) = match &dots_options.pattern {
+            Some(pattern) => pattern_fill(
+                &name,
+                pattern,
+                (x_beginning, y_beginning, side, side),
+                dot_size,
+                0.0,
+            ),
+            None => self.create_color(
+                dots_options.gradient.as_ref(),
+                &dots_options.color,
+                0.0,
+                0.0,
+                0.0,
+                self.options.height as f64,
+                self.options.width as f64,
+                &name,
+            ),
+        };
         defs.push_str(&grad_defs);
 
         let elements = format!(
-            r#"<rect x="0" y="0" width="{}" height="{}" fill="{}" clip-path="url(#clip-path-{})"/>
+            r#"{}<rect x="0" y="0" width="{}" height="{}" fill="{}" clip-path="url(#clip-path-{})"{}/>
 "#,
-            self.options.width, self.options.height, fill, name
+            under, self.options.width, self.options.height, fill, name, mask
         );
 
         (defs, elements)
     }
 
+    /// Paint one colored cell under each dot, one path per color.
+    ///
+    /// Cells next to a dot are painted too, so jittered dots and liquid
+    /// bridges that cross into a light cell are still filled; the dots clip
+    /// path keeps only the dot shapes.
+    #[allow(clippy::too_many_arguments)]
+    fn render_module_colors(
+        &self,
+        matrix: &QRMatrix,
+        count: usize,
+        dot_size: f64,
+        x_beginning: f64,
+        y_beginning: f64,
+        dots_options: &DotsOptions,
+        decorate: bool,
+        is_drawn: &dyn Fn(usize, usize) -> bool,
+    ) -> String {
+        let layout = matrix.layout();
+        let drawn = |row: isize, col: isize| -> bool {
+            row >= 0
+                && col >= 0
+                && (row as usize) < count
+                && (col as usize) < count
+                && is_drawn(row as usize, col as usize)
+        };
+
+        // Decorative dots around a circle extend past the symbol.
+        let extra = if self.options.shape == ShapeType::Circle && decorate {
+            self.circle_additional_dots(count, dot_size) as isize
+        } else {
+            0
+        };
+        let half = count as f64 / 2.0;
+
+        let mut paths: Vec<(String, String)> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for row in -extra..count as isize + extra {
+            for col in -extra..count as isize + extra {
+                let inside = row >= 0 && col >= 0 && row < count as isize && col < count as isize;
+                let dark = drawn(row, col);
+                if inside && !(-1..=1).any(|dy| (-1..=1).any(|dx| drawn(row + dy, col + dx))) {
+                    continue;
+                }
+
+                let info = ModuleInfo {
+                    count,
+                    kind: inside.then(|| layout.kind(row as usize, col as usize)),
+                    dark: dark || !inside,
+                    distance: (row as f64 + 0.5 - half).hypot(col as f64 + 0.5 - half) / half,
+                };
+                // Decorative cells continue the palette pattern from the
+                // opposite edge.
+                let (r, c) = (
+                    row.rem_euclid(count as isize) as usize,
+                    col.rem_euclid(count as isize) as usize,
+                );
+                let fill = dots_options.module_color(r, c, info).to_hex();
+
+                let x = x_beginning + col as f64 * dot_size;
+                let y = y_beginning + row as f64 * dot_size;
+                let cell = format!(
+                    "M {} {} h {} v {} h {} Z ",
+                    x, y, dot_size, dot_size, -dot_size
+                );
+                match index.get(&fill) {
+                    Some(&i) => paths[i].1.push_str(&cell),
+                    None => {
+                        index.insert(fill.clone(), paths.len());
+                        paths.push((fill, cell));
+                    }
+                }
+            }
+        }
+
+        paths
+            .iter()
+            .map(|(fill, d)| format!("<path fill=\"{}\" d=\"{}\"/>\n", fill, d.trim_end()))
+            .collect()
+    }
+
+    /// Rings of decorative dots added around a circle-shaped code.
+    fn circle_additional_dots(&self, count: usize, dot_size: f64) -> usize {
+        let min_size =
+            (self.options.width.min(self.options.height) - self.options.margin * 2) as f64;
+        self.round_size((min_size / dot_size - count as f64) / 2.0) as usize
+    }
+
     fn render_circle_edge_dots(
         &self,
         matrix: &QRMatrix,
@@ -288,8 +748,7 @@ impl SvgRenderer {
         dot_drawer: &QRDot,
     ) -> String {
         let mut result = String::new();
-        let min_size = (self.options.width.min(self.options.height) - self.options.margin * 2) as f64;
-        let additional_dots = self.round_size((min_size / dot_size - count as f64) / 2.0) as usize;
+        let additional_dots = self.circle_additional_dots(count, dot_size);
         let fake_count = count + additional_dots * 2;
         let x_fake_beginning = x_beginning - additional_dots as f64 * dot_size;
         let y_fake_beginning = y_beginning - additional_dots as f64 * dot_size;
@@ -297,6 +756,7 @@ impl SvgRenderer {
 
         let mut fake_matrix = vec![vec![0u8; fake_count]; fake_count];
 
+        #[allow(clippy::needless_range_loop)]
         for row in 0..fake_count {
             for col in 0..fake_count {
                 // Skip inner area
@@ -354,7 +814,7 @@ impl SvgRenderer {
                     fake_matrix[new_row as usize][new_col as usize] == 1
                 };
 
-                let svg = dot_drawer.draw(x, y, dot_size, Some(&neighbor_fn));
+                let svg = dot_drawer.draw_module(row, col, x, y, dot_size, Some(&neighbor_fn));
                 result.push_str(&svg);
                 result.push('\n');
             }
@@ -363,6 +823,93 @@ impl SvgRenderer {
         result
     }
 
+    /// Centers of the alignment patterns drawn whole by the alignment
+    /// options; patterns the logo partly hides are left to the dots.
+    fn alignment_units(
+        &self,
+        layout: &QRLayout,
+        count: usize,
+        hide_x_dots: usize,
+        hide_y_dots: usize,
+    ) -> Vec<(usize, usize)> {
+        if self.options.alignment_options.is_none() {
+            return Vec::new();
+        }
+        layout
+            .alignment_centers()
+            .into_iter()
+            .filter(|&(row, col)| {
+                (row - 2..=row + 2).all(|r| {
+                    (col - 2..=col + 2)
+                        .all(|c| self.should_draw_dot(r, c, count, hide_x_dots, hide_y_dots))
+                })
+            })
+            .collect()
+    }
+
+    /// Draw each alignment pattern as a ring and a center, like the corners.
+    fn render_alignment(
+        &self,
+        matrix: &QRMatrix,
+        count: usize,
+        dot_size: f64,
+        hide_x_dots: usize,
+        hide_y_dots: usize,
+    ) -> (String, String) {
+        let Some(options) = &self.options.alignment_options else {
+            return (String::new(), String::new());
+        };
+        let units = self.alignment_units(&matrix.layout(), count, hide_x_dots, hide_y_dots);
+        if units.is_empty() {
+            return (String::new(), String::new());
+        }
+
+        let (x_beginning, y_beginning) = self.grid_origin(count);
+        let ring = QRCornerSquare::new(options.ring_type)
+            .with_modules(5)
+            .with_radius(options.radius);
+        let center = QRCornerDot::new(options.center_type).with_radius(options.radius);
+
+        let mut clip_path_content = String::new();
+        for (row, col) in units {
+            let x = x_beginning + (col - 2) as f64 * dot_size;
+            let y = y_beginning + (row - 2) as f64 * dot_size;
+            clip_path_content.push_str(&ring.draw(x, y, dot_size * 5.0, 0.0));
+            clip_path_content.push('\n');
+            let (cx, cy) = (x + dot_size * 2.0, y + dot_size * 2.0);
+            clip_path_content.push_str(&center.draw(cx, cy, dot_size, 0.0));
+            clip_path_content.push('\n');
+        }
+
+        let name = format!("alignment-units-{}", self.instance_id);
+        let mut defs = format!(
+            r#"<clipPath id="clip-path-{}">
+{}</clipPath>
+"#,
+            name, clip_path_content
+        );
+
+        let size = count as f64 * dot_size;
+        let (grad_defs, fill) = self.create_color(
+            options.gradient.as_ref(),
+            &options.color,
+            0.0,
+            x_beginning,
+            y_beginning,
+            size,
+            size,
+            &name,
+        );
+        defs.push_str(&grad_defs);
+
+        let elements = format!(
+            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}" clip-path="url(#clip-path-{})"/>
+"#,
+            x_beginning, y_beginning, size, size, fill, name
+        );
+        (defs, elements)
+    }
+
     fn render_corners(&self, count: usize, dot_size: f64) -> (String, String) {
         let mut defs = String::new();
         let mut elements = String::new();
@@ -373,31 +920,51 @@ impl SvgRenderer {
         let corners_square_size = dot_size * 7.0;
         let corners_dot_size = dot_size * 3.0;
 
-        // Three corners: top-left, top-right, bottom-left
-        let corner_positions = [
-            (0, 0, 0.0),
-            (1, 0, PI / 2.0),
-            (0, 1, -PI / 2.0),
-        ];
-
-        for (column, row, rotation) in corner_positions {
+        let corners = &self.options.corners_options;
+        for corner in FinderCorner::ALL {
+            let (row, column) = match corner {
+                FinderCorner::TopLeft => (0, 0),
+                FinderCorner::TopRight => (0, 1),
+                FinderCorner::BottomLeft => (1, 0),
+            };
             let x = x_beginning + column as f64 * dot_size * (count - 7) as f64;
             let y = y_beginning + row as f64 * dot_size * (count - 7) as f64;
+            let (rotation, mirror) = corner_orientation(
+                corners.orientation,
+                corner,
+                x + corners_square_size / 2.0,
+                y + corners_square_size / 2.0,
+            );
+            let style = corners.corner(corner);
 
             // Render corner square
             let (sq_defs, sq_elements) = self.render_corner_square(
-                x, y, corners_square_size, dot_size, rotation, column, row,
+                style
+                    .square
+                    .as_ref()
+                    .unwrap_or(&self.options.corners_square_options),
+                x,
+                y,
+                corners_square_size,
+                rotation,
+                mirror.as_deref(),
+                column,
+                row,
             );
             defs.push_str(&sq_defs);
             elements.push_str(&sq_elements);
 
             // Render corner dot
             let (dot_defs, dot_elements) = self.render_corner_dot(
+                style
+                    .dot
+                    .as_ref()
+                    .unwrap_or(&self.options.corners_dot_options),
                 x + dot_size * 2.0,
                 y + dot_size * 2.0,
                 corners_dot_size,
-                dot_size,
                 rotation,
+                mirror.as_deref(),
                 column,
                 row,
             );
@@ -408,13 +975,15 @@ impl SvgRenderer {
         (defs, elements)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn render_corner_square(
         &self,
+        sq_options: &CornersSquareOptions,
         x: f64,
         y: f64,
         size: f64,
-        _dot_size: f64,
         rotation: f64,
+        mirror: Option<&str>,
         column: usize,
         row: usize,
     ) -> (String, String) {
@@ -423,16 +992,23 @@ impl SvgRenderer {
 
         let name = format!("corners-square-color-{}-{}-{}", column, row, self.instance_id);
 
-        let sq_options = &self.options.corners_square_options;
-
-        // Use corner square drawer if specific type is set
-        match sq_options.square_type {
-            CornerSquareType::Square | CornerSquareType::Dot | CornerSquareType::ExtraRounded => {
-                let drawer = QRCornerSquare::new(sq_options.square_type);
-                let svg = drawer.draw(x, y, size, rotation);
-                clip_path_content.push_str(&svg);
+        let artwork = match sq_options.square_type {
+            CornerSquareType::Custom => sq_options.artwork.as_ref(),
+            _ => None,
+        };
+        let svg = match artwork {
+            Some(CornerArtwork::Image(image)) => {
+                return (
+                    String::new(),
+                    artwork_image(image, x, y, size, rotation, mirror),
+                );
             }
-        }
+            Some(CornerArtwork::Path(d)) => artwork_path(d, 7.0, x, y, size, rotation),
+            None => QRCornerSquare::new(sq_options.square_type)
+                .with_radius(sq_options.radius.min(sq_options.square_type.max_radius()))
+                .draw(x, y, size, rotation),
+        };
+        clip_path_content.push_str(&svg);
 
         defs.push_str(&format!(
             r#"<clipPath id="clip-path-{}">
@@ -441,36 +1017,50 @@ impl SvgRenderer {
 "#,
             name, clip_path_content
         ));
-
-        // Create color
-        let (grad_defs, fill) = self.create_color(
-            sq_options.gradient.as_ref(),
-            &sq_options.color,
-            rotation,
-            x,
-            y,
-            size,
-            size,
+        let outline = sq_options.outline.as_ref().filter(|_| artwork.is_none());
+        let (under, mask) = self.outline_layer(
             &name,
+            &clip_path_content,
+            outline,
+            size / 7.0,
+            (x, y, size, size),
+            &mut defs,
         );
+
+        // Create color
+        let (grad_defs, fill) = match &sq_options.pattern {
+            Some(pattern) => pattern_fill(&name, pattern, (x, y, size, size), size / 7.0, rotation),
+            None => self.create_color(
+                sq_options.gradient.as_ref(),
+                &sq_options.color,
+                rotation,
+                x,
+                y,
+                size,
+                size,
+                &name,
+            ),
+        };
         defs.push_str(&grad_defs);
 
         let elements = format!(
-            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}" clip-path="url(#clip-path-{})"/>
+            r#"{}<rect x="{}" y="{}" width="{}" height="{}" fill="{}" clip-path="url(#clip-path-{})"{}/>
 "#,
-            x, y, size, size, fill, name
+            under, x, y, size, size, fill, name, mask
         );
 
-        (defs, elements)
+        (defs, mirrored_group(elements, mirror))
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn render_corner_dot(
         &self,
+        dot_options: &CornersDotOptions,
         x: f64,
         y: f64,
         size: f64,
-        _dot_size: f64,
         rotation: f64,
+        mirror: Option<&str>,
         column: usize,
         row: usize,
     ) -> (String, String) {
@@ -479,11 +1069,22 @@ impl SvgRenderer {
 
         let name = format!("corners-dot-color-{}-{}-{}", column, row, self.instance_id);
 
-        let dot_options = &self.options.corners_dot_options;
-
-        // Use corner dot drawer
-        let drawer = QRCornerDot::new(dot_options.dot_type);
-        let svg = drawer.draw(x, y, size, rotation);
+        let artwork = match dot_options.dot_type {
+            CornerDotType::Custom => dot_options.artwork.as_ref(),
+            _ => None,
+        };
+        let svg = match artwork {
+            Some(CornerArtwork::Image(image)) => {
+                return (
+                    String::new(),
+                    artwork_image(image, x, y, size, rotation, mirror),
+                );
+            }
+            Some(CornerArtwork::Path(d)) => artwork_path(d, 3.0, x, y, size, rotation),
+            None => QRCornerDot::new(dot_options.dot_type)
+                .with_radius(dot_options.radius.min(dot_options.dot_type.max_radius()))
+                .draw(x, y, size, rotation),
+        };
         clip_path_content.push_str(&svg);
 
         defs.push_str(&format!(
@@ -493,27 +1094,100 @@ impl SvgRenderer {
 "#,
             name, clip_path_content
         ));
-
-        // Create color
-        let (grad_defs, fill) = self.create_color(
-            dot_options.gradient.as_ref(),
-            &dot_options.color,
-            rotation,
-            x,
-            y,
-            size,
-            size,
+        let outline = dot_options.outline.as_ref().filter(|_| artwork.is_none());
+        let (under, mask) = self.outline_layer(
             &name,
+            &clip_path_content,
+            outline,
+            size / 3.0,
+            (x, y, size, size),
+            &mut defs,
         );
+
+        // Create color
+        let (grad_defs, fill) = match &dot_options.pattern {
+            Some(pattern) => pattern_fill(&name, pattern, (x, y, size, size), size / 3.0, rotation),
+            None => self.create_color(
+                dot_options.gradient.as_ref(),
+                &dot_options.color,
+                rotation,
+                x,
+                y,
+                size,
+                size,
+                &name,
+            ),
+        };
         defs.push_str(&grad_defs);
 
         let elements = format!(
-            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}" clip-path="url(#clip-path-{})"/>
+            r#"{}<rect x="{}" y="{}" width="{}" height="{}" fill="{}" clip-path="url(#clip-path-{})"{}/>
 "#,
-            x, y, size, size, fill, name
+            under, x, y, size, size, fill, name, mask
         );
 
-        (defs, elements)
+        (defs, mirrored_group(elements, mirror))
+    }
+
+    /// Inner fill and `mask` attribute for drawing the shapes in clip path
+    /// `name` as outlines; both are empty without `outline`.
+    ///
+    /// Each line is a stroke along the shape edges, twice as wide as the
+    /// line is deep because the clip path cuts away its outer half. Masking
+    /// deeper strokes out again leaves the gap of a double line.
+    fn outline_layer(
+        &self,
+        name: &str,
+        shapes: &str,
+        outline: Option<&OutlineOptions>,
+        module: f64,
+        (x, y, width, height): (f64, f64, f64, f64),
+        defs: &mut String,
+    ) -> (String, String) {
+        let Some(outline) = outline else {
+            return (String::new(), String::new());
+        };
+
+        let mut strokes = String::new();
+        for (start, end) in outline.bands().into_iter().rev() {
+            strokes.push_str(&format!(
+                "<g stroke=\"white\" stroke-width=\"{}\">\n{}</g>\n",
+                2.0 * end * module,
+                shapes
+            ));
+            if start > 0.0 {
+                strokes.push_str(&format!(
+                    "<g stroke=\"black\" stroke-width=\"{}\">\n{}</g>\n",
+                    2.0 * start * module,
+                    shapes
+                ));
+            }
+        }
+        defs.push_str(&format!(
+            r#"<mask id="outline-{}" maskUnits="userSpaceOnUse" x="0" y="0" width="{}" height="{}">
+<g fill="none">
+{}</g>
+</mask>
+"#,
+            name, self.options.width, self.options.height, strokes
+        ));
+
+        let under = outline
+            .fill
+            .map(|fill| {
+                format!(
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}" clip-path="url(#clip-path-{})"/>
+"#,
+                    x,
+                    y,
+                    width,
+                    height,
+                    fill.to_hex(),
+                    name
+                )
+            })
+            .unwrap_or_default();
+        (under, format!(r#" mask="url(#outline-{})""#, name))
     }
 
     fn render_image(
@@ -536,21 +1210,7 @@ impl SvgRenderer {
         let dw = width - margin * 2.0;
         let dh = height - margin * 2.0;
 
-        // Encode image as base64 data URL
-        let base64_data = base64::Engine::encode(&base64::engine::general_purpose::STANDARD, image_data);
-
-        // Detect mime type from image data
-        let mime_type = if image_data.starts_with(&[0x89, 0x50, 0x4E, 0x47]) {
-            "image/png"
-        } else if image_data.starts_with(&[0xFF, 0xD8]) {
-            "image/jpeg"
-        } else if image_data.starts_with(b"RIFF") && image_data.len() > 12 && &image_data[8..12] == b"WEBP" {
-            "image/webp"
-        } else {
-            "image/png" // Default
-        };
-
-        let data_url = format!("data:{};base64,{}", mime_type, base64_data);
+        let data_url = data_url(image_data);
 
         format!(
             r#"<image href="{}" xlink:href="{}" x="{}" y="{}" width="{}px" height="{}px"/>
@@ -559,6 +1219,116 @@ impl SvgRenderer {
         )
     }
 
+    /// Draw the halftone picture over the code area, function patterns in
+    /// full and a small exact square (in the dot style) at each module center.
+    fn render_halftone(
+        &self,
+        matrix: &QRMatrix,
+        plan: &Halftone,
+        count: usize,
+        dot_size: f64,
+        hide_x_dots: usize,
+        hide_y_dots: usize,
+    ) -> (String, String) {
+        let (x_beginning, y_beginning) = self.grid_origin(count);
+        let halftone = self.options.halftone.as_ref().expect("halftone options");
+        let layout = matrix.layout();
+        let dot_drawer = QRDot::new(self.options.dots_options.dot_type)
+            .with_custom_drawer(self.options.dots_options.custom_drawer.clone());
+        let center = halftone.center_size * dot_size;
+        let units = self.alignment_units(&layout, count, hide_x_dots, hide_y_dots);
+
+        let (mut dark_cells, mut light_cells) = (String::new(), String::new());
+        let (mut dark_centers, mut light_centers) = (String::new(), String::new());
+        for row in 0..count {
+            for col in 0..count {
+                let x = x_beginning + col as f64 * dot_size;
+                let y = y_beginning + row as f64 * dot_size;
+                let cell = format!(
+                    "M {} {} h {} v {} h {} Z ",
+                    x, y, dot_size, dot_size, -dot_size
+                );
+                match layout.kind(row, col) {
+                    // Corners and alignment units are drawn on top; keep the
+                    // picture out of them.
+                    ModuleKind::Finder | ModuleKind::Separator => light_cells.push_str(&cell),
+                    ModuleKind::Alignment if in_alignment_unit(&units, row, col) => {
+                        light_cells.push_str(&cell)
+                    }
+                    kind if kind.is_function() => {
+                        if matrix.is_dark(row, col) {
+                            dark_cells.push_str(&cell);
+                        } else {
+                            light_cells.push_str(&cell);
+                        }
+                    }
+                    _ => {
+                        if !self.should_draw_dot(row, col, count, hide_x_dots, hide_y_dots) {
+                            continue;
+                        }
+                        let offset = (dot_size - center) / 2.0;
+                        let svg = dot_drawer.draw(
+                            x + offset,
+                            y + offset,
+                            center,
+                            None::<fn(i32, i32) -> bool>,
+                        );
+                        if plan.is_dark(row, col) {
+                            dark_centers.push_str(&svg);
+                        } else {
+                            light_centers.push_str(&svg);
+                        }
+                    }
+                }
+            }
+        }
+
+        let name = format!("dot-color-{}", self.instance_id);
+        let (defs, dark_fill) = self.create_color(
+            self.options.dots_options.gradient.as_ref(),
+            &self.options.dots_options.color,
+            0.0,
+            0.0,
+            0.0,
+            self.options.height as f64,
+            self.options.width as f64,
+            &name,
+        );
+        let light_fill = self
+            .options
+            .background_options
+            .color
+            .over(&Color::WHITE)
+            .to_hex();
+
+        let side = count as f64 * dot_size;
+        let picture = data_url(&plan.picture);
+        let elements = format!(
+            r#"<image href="{}" xlink:href="{}" x="{}" y="{}" width="{}" height="{}" preserveAspectRatio="none"/>
+<path fill="{}" d="{}"/>
+<path fill="{}" d="{}"/>
+<g fill="{}">{}</g>
+<g fill="{}">{}</g>
+"#,
+            picture,
+            picture,
+            x_beginning,
+            y_beginning,
+            side,
+            side,
+            light_fill,
+            light_cells.trim_end(),
+            dark_fill,
+            dark_cells.trim_end(),
+            light_fill,
+            light_centers,
+            dark_fill,
+            dark_centers
+        );
+        (defs, elements)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn create_color(
         &self,
         gradient: Option<&Gradient>,
@@ -574,86 +1344,70 @@ impl SvgRenderer {
 
         if let Some(grad) = gradient {
             let size = width.max(height);
+            let middle = (x + width / 2.0, y + height / 2.0);
+            // Relative geometry turns with the element, like `rotation`.
+            let point = |(px, py): (f64, f64)| match grad.units {
+                GradientUnits::Relative => rotate_point(
+                    (x + px * width, y + py * height),
+                    middle,
+                    additional_rotation,
+                ),
+                GradientUnits::Absolute => (px, py),
+            };
+            let spread = match grad.spread {
+                SpreadMethod::Pad => String::new(),
+                spread => format!(r#" spreadMethod="{}""#, spread.as_str()),
+            };
 
             match grad.gradient_type {
                 GradientType::Radial => {
-                    let cx = x + width / 2.0;
-                    let cy = y + height / 2.0;
-                    let r = size / 2.0;
+                    let (cx, cy) = grad.center.map(point).unwrap_or(middle);
+                    let (fx, fy) = grad.focal.map(point).unwrap_or((cx, cy));
+                    let r = match (grad.radius, grad.units) {
+                        (Some(r), GradientUnits::Relative) => r * size,
+                        (Some(r), GradientUnits::Absolute) => r,
+                        (None, _) => size / 2.0,
+                    };
 
                     defs.push_str(&format!(
-                        r#"<radialGradient id="{}" gradientUnits="userSpaceOnUse" fx="{}" fy="{}" cx="{}" cy="{}" r="{}">
+                        r#"<radialGradient id="{}" gradientUnits="userSpaceOnUse" fx="{}" fy="{}" cx="{}" cy="{}" r="{}"{}>
 "#,
-                        name, cx, cy, cx, cy, r
+                        name, fx, fy, cx, cy, r, spread
                     ));
 
-                    for stop in &grad.color_stops {
-                        defs.push_str(&format!(
-                            r#"<stop offset="{}%" stop-color="{}"/>
-"#,
-                            stop.offset * 100.0,
-                            stop.color.to_hex()
-                        ));
-                    }
+                    defs.push_str(&gradient_stops(grad));
 
                     defs.push_str("</radialGradient>\n");
                 }
                 GradientType::Linear => {
-                    let rotation = (grad.rotation + additional_rotation) % (2.0 * PI);
-                    let positive_rotation = (rotation + 2.0 * PI) % (2.0 * PI);
-
-                    let (mut x0, mut y0, mut x1, mut y1) = (
-                        x + width / 2.0,
-                        y + height / 2.0,
-                        x + width / 2.0,
-                        y + height / 2.0,
-                    );
-
-                    if (positive_rotation >= 0.0 && positive_rotation <= 0.25 * PI)
-                        || (positive_rotation > 1.75 * PI && positive_rotation <= 2.0 * PI)
-                    {
-                        x0 -= width / 2.0;
-                        y0 -= (height / 2.0) * rotation.tan();
-                        x1 += width / 2.0;
-                        y1 += (height / 2.0) * rotation.tan();
-                    } else if positive_rotation > 0.25 * PI && positive_rotation <= 0.75 * PI {
-                        y0 -= height / 2.0;
-                        x0 -= (width / 2.0) / rotation.tan();
-                        y1 += height / 2.0;
-                        x1 += (width / 2.0) / rotation.tan();
-                    } else if positive_rotation > 0.75 * PI && positive_rotation <= 1.25 * PI {
-                        x0 += width / 2.0;
-                        y0 += (height / 2.0) * rotation.tan();
-                        x1 -= width / 2.0;
-                        y1 -= (height / 2.0) * rotation.tan();
-                    } else if positive_rotation > 1.25 * PI && positive_rotation <= 1.75 * PI {
-                        y0 += height / 2.0;
-                        x0 += (width / 2.0) / rotation.tan();
-                        y1 -= height / 2.0;
-                        x1 -= (width / 2.0) / rotation.tan();
-                    }
+                    let ((x0, y0), (x1, y1)) = match grad.points {
+                        Some((start, end)) => (point(start), point(end)),
+                        None => linear_endpoints(
+                            grad.rotation + additional_rotation,
+                            (x, y, width, height),
+                        ),
+                    };
 
                     defs.push_str(&format!(
-                        r#"<linearGradient id="{}" gradientUnits="userSpaceOnUse" x1="{}" y1="{}" x2="{}" y2="{}">
+                        r#"<linearGradient id="{}" gradientUnits="userSpaceOnUse" x1="{}" y1="{}" x2="{}" y2="{}"{}>
 "#,
-                        name,
-                        x0.round(),
-                        y0.round(),
-                        x1.round(),
-                        y1.round()
+                        name, x0, y0, x1, y1, spread
                     ));
 
-                    for stop in &grad.color_stops {
-                        defs.push_str(&format!(
-                            r#"<stop offset="{}%" stop-color="{}"/>
-"#,
-                            stop.offset * 100.0,
-                            stop.color.to_hex()
-                        ));
-                    }
+                    defs.push_str(&gradient_stops(grad));
 
                     defs.push_str("</linearGradient>\n");
                 }
+                GradientType::Conic => {
+                    let (cx, cy) = grad.center.map(point).unwrap_or(middle);
+                    defs.push_str(&conic_pattern(
+                        name,
+                        grad,
+                        grad.rotation + additional_rotation,
+                        (x, y, width, height),
+                        (cx - x, cy - y),
+                    ));
+                }
             }
 
             (defs, format!("url(#{})", name))
@@ -684,10 +1438,8 @@ impl SvgRenderer {
 
         // Skip corner squares (finder patterns)
         // Top-left
-        if row < 7 && col < 7 {
-            if SQUARE_MASK[row][col] == 1 || DOT_MASK[row][col] == 1 {
-                return false;
-            }
+        if row < 7 && col < 7 && (SQUARE_MASK[row][col] == 1 || DOT_MASK[row][col] == 1) {
+            return false;
         }
 
         // Top-right
@@ -709,33 +1461,394 @@ impl SvgRenderer {
         true
     }
 
-    fn calculate_image_hide_area(&self, count: usize, _dot_size: f64) -> (usize, usize) {
-        // Calculate based on error correction level and image size
-        let error_correction_percent = self.options.qr_options.error_correction_level.percentage();
-        let cover_level = self.options.image_options.image_size * error_correction_percent;
-        let max_hidden_dots = (cover_level * (count * count) as f64).floor() as usize;
-        let max_hidden_axis_dots = count.saturating_sub(14);
+    fn round_size(&self, value: f64) -> f64 {
+        if self.options.dots_options.round_size {
+            value.floor()
+        } else {
+            value
+        }
+    }
+}
+
+/// Rounded endpoints of a linear gradient at `rotation` radians across
+/// the area, from edge to edge.
+fn linear_endpoints(
+    rotation: f64,
+    (x, y, width, height): (f64, f64, f64, f64),
+) -> ((f64, f64), (f64, f64)) {
+    let rotation = rotation % (2.0 * PI);
+    let positive_rotation = (rotation + 2.0 * PI) % (2.0 * PI);
+
+    let (mut x0, mut y0, mut x1, mut y1) = (
+        x + width / 2.0,
+        y + height / 2.0,
+        x + width / 2.0,
+        y + height / 2.0,
+    );
+
+    if (0.0..=0.25 * PI).contains(&positive_rotation)
+        || (positive_rotation > 1.75 * PI && positive_rotation <= 2.0 * PI)
+    {
+        x0 -= width / 2.0;
+        y0 -= (height / 2.0) * rotation.tan();
+        x1 += width / 2.0;
+        y1 += (height / 2.0) * rotation.tan();
+    } else if positive_rotation > 0.25 * PI && positive_rotation <= 0.75 * PI {
+        y0 -= height / 2.0;
+        x0 -= (width / 2.0) / rotation.tan();
+        y1 += height / 2.0;
+        x1 += (width / 2.0) / rotation.tan();
+    } else if positive_rotation > 0.75 * PI && positive_rotation <= 1.25 * PI {
+        x0 += width / 2.0;
+        y0 += (height / 2.0) * rotation.tan();
+        x1 -= width / 2.0;
+        y1 -= (height / 2.0) * rotation.tan();
+    } else if positive_rotation > 1.25 * PI && positive_rotation <= 1.75 * PI {
+        y0 += height / 2.0;
+        x0 += (width / 2.0) / rotation.tan();
+        y1 -= height / 2.0;
+        x1 -= (width / 2.0) / rotation.tan();
+    }
+
+    ((x0.round(), y0.round()), (x1.round(), y1.round()))
+}
+
+/// `point` turned by `angle` radians around `center`.
+fn rotate_point((px, py): (f64, f64), (cx, cy): (f64, f64), angle: f64) -> (f64, f64) {
+    let (sin, cos) = angle.sin_cos();
+    let (dx, dy) = (px - cx, py - cy);
+    (cx + dx * cos - dy * sin, cy + dx * sin + dy * cos)
+}
+
+/// `<stop>` elements of a linear or radial gradient.
+///
+/// Alpha goes in `stop-opacity`, since many consumers ignore 8-digit hex.
+fn gradient_stops(gradient: &Gradient) -> String {
+    let mut stops = String::new();
+    for stop in &gradient.color_stops {
+        let color = stop.color;
+        stops.push_str(&format!(
+            r#"<stop offset="{}%" stop-color="{}""#,
+            stop.offset * 100.0,
+            Color::rgb(color.r, color.g, color.b).to_hex()
+        ));
+        if color.a < 255 {
+            stops.push_str(&format!(r#" stop-opacity="{}""#, color.a as f64 / 255.0));
+        }
+        stops.push_str("/>\n");
+    }
+    stops
+}
+
+/// `<pattern>` painting a conic gradient over the area `x`/`y`/`width`/
+/// `height`, starting at `start` radians clockwise from the top around
+/// `center` (relative to the area).
+///
+/// SVG has no conic gradient, so it is drawn as thin wedges of solid color,
+/// each overlapping the next to hide anti-aliasing seams. Stop opacity is
+/// applied through a mask of the same wedges in gray, so the overlaps don't
+/// show through translucent colors.
+fn conic_pattern(
+    name: &str,
+    gradient: &Gradient,
+    start: f64,
+    (x, y, width, height): (f64, f64, f64, f64),
+    (cx, cy): (f64, f64),
+) -> String {
+    // Far enough out that the wedge chords clear every corner of the area.
+    let reach = [(0.0, 0.0), (width, 0.0), (0.0, height), (width, height)]
+        .iter()
+        .map(|&(px, py): &(f64, f64)| (px - cx).hypot(py - cy))
+        .fold(0.0, f64::max)
+        * 1.01
+        + 1.0;
+    let point = |angle: f64| (cx + reach * angle.sin(), cy - reach * angle.cos());
+
+    let step = 2.0 * PI / CONIC_STEPS as f64;
+    let wedges = |fill: &dyn Fn(Color) -> Color| -> String {
+        let mut paths = String::new();
+        for i in 0..CONIC_STEPS {
+            let color = fill(gradient.color_at((i as f64 + 0.5) / CONIC_STEPS as f64));
+            // The last wedge stops at the start angle, keeping it sharp.
+            let overlap = if i + 1 < CONIC_STEPS { step * 0.5 } else { 0.0 };
+            let (x0, y0) = point(start + i as f64 * step);
+            let (x1, y1) = point(start + (i + 1) as f64 * step + overlap);
+            paths.push_str(&format!(
+                "<path d=\"M {} {} L {} {} L {} {} Z\" fill=\"{}\"/>\n",
+                cx,
+                cy,
+                x0,
+                y0,
+                x1,
+                y1,
+                color.to_hex()
+            ));
+        }
+        paths
+    };
+
+    let colors = wedges(&|c| Color::rgb(c.r, c.g, c.b));
+    let content = if gradient.color_stops.iter().all(|stop| stop.color.a == 255) {
+        colors
+    } else {
+        format!(
+            r#"<mask id="{}-opacity" maskUnits="userSpaceOnUse" x="0" y="0" width="{}" height="{}">
+{}</mask>
+<g mask="url(#{}-opacity)">
+{}</g>
+"#,
+            name,
+            width,
+            height,
+            wedges(&|c| Color::rgb(c.a, c.a, c.a)),
+            name,
+            colors
+        )
+    };
 
-        // Simple calculation for image area
-        // Use aspect ratio 1:1 for simplicity (can be enhanced with actual image dimensions)
-        let mut hide_dots = (max_hidden_dots as f64).sqrt().floor() as usize;
+    format!(
+        r#"<pattern id="{}" patternUnits="userSpaceOnUse" x="{}" y="{}" width="{}" height="{}">
+{}</pattern>
+"#,
+        name, x, y, width, height, content
+    )
+}
 
-        // Ensure odd number for center alignment
-        if hide_dots % 2 == 0 {
-            hide_dots = hide_dots.saturating_sub(1);
+/// Defs and fill for a pattern fill over `area`, with tiles `module`
+/// pixels per module starting at its top-left corner. The pattern turns by
+/// `rotation` radians around the area's center, on top of its own rotation.
+fn pattern_fill(
+    name: &str,
+    pattern: &PatternFill,
+    (x, y, width, height): (f64, f64, f64, f64),
+    module: f64,
+    rotation: f64,
+) -> (String, String) {
+    let (tile_width, tile_height) = match (&pattern.kind, pattern.tile) {
+        (_, Some(tile)) => (tile * module, tile * module),
+        (PatternKind::Image(_), None) => (width, height),
+        (_, None) => (module, module),
+    };
+    let (t, color) = (tile_width, fill_attributes(&pattern.color));
+
+    let mut content = format!(
+        "<rect shape-rendering=\"crispEdges\" width=\"{}\" height=\"{}\" {}/>\n",
+        tile_width,
+        tile_height,
+        fill_attributes(&pattern.background)
+    );
+    match &pattern.kind {
+        PatternKind::Stripes => {
+            content.push_str(&format!(
+                "<rect shape-rendering=\"crispEdges\" width=\"{}\" height=\"{}\" {}/>\n",
+                t / 2.0,
+                t,
+                color
+            ));
+        }
+        PatternKind::Crosshatch => {
+            let stroke = color.replace("fill", "stroke");
+            content.push_str(&format!(
+                "<path d=\"M 0 0 L {t} {t} M {t} 0 L 0 {t}\" fill=\"none\" stroke-width=\"{}\" {}/>\n",
+                t / 5.0,
+                stroke
+            ));
+        }
+        PatternKind::Polka => {
+            for (cx, cy) in [(t / 2.0, t / 2.0), (0.0, 0.0), (t, 0.0), (0.0, t), (t, t)] {
+                content.push_str(&format!(
+                    "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" {}/>\n",
+                    cx,
+                    cy,
+                    t / 5.0,
+                    color
+                ));
+            }
+        }
+        PatternKind::Noise => {
+            // Cells are drawn without anti-aliasing so no seams show between them.
+            let cells = 8;
+            let cell = t / cells as f64;
+            content.push_str("<g shape-rendering=\"crispEdges\">\n");
+            for row in 0..cells {
+                for col in 0..cells {
+                    let mix = SeededRng::for_cell(pattern.seed, row, col).next_f64();
+                    content.push_str(&format!(
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" {}/>\n",
+                        col as f64 * cell,
+                        row as f64 * cell,
+                        cell,
+                        cell,
+                        fill_attributes(&pattern.color.mix(&pattern.background, mix))
+                    ));
+                }
+            }
+            content.push_str("</g>\n");
+        }
+        PatternKind::Image(image) => {
+            let url = data_url(image);
+            content = format!(
+                r#"<image href="{}" xlink:href="{}" width="{}" height="{}" preserveAspectRatio="xMidYMid slice"/>
+"#,
+                url, url, tile_width, tile_height
+            );
         }
+    }
 
-        // Clamp to max
-        hide_dots = hide_dots.min(max_hidden_axis_dots);
+    let angle = (pattern.rotation + rotation).to_degrees();
+    let transform = if angle == 0.0 {
+        String::new()
+    } else {
+        format!(
+            r#" patternTransform="rotate({} {} {})""#,
+            angle,
+            x + width / 2.0,
+            y + height / 2.0
+        )
+    };
+    let defs = format!(
+        r#"<pattern id="{}" patternUnits="userSpaceOnUse" x="{}" y="{}" width="{}" height="{}"{}>
+{}</pattern>
+"#,
+        name, x, y, tile_width, tile_height, transform, content
+    );
+    (defs, format!("url(#{})", name))
+}
 
-        (hide_dots, hide_dots)
+/// `fill` attribute for `color`, with its alpha in `fill-opacity` if it is
+/// translucent.
+fn fill_attributes(color: &Color) -> String {
+    let opaque = Color::rgb(color.r, color.g, color.b).to_hex();
+    if color.a == 255 {
+        format!("fill=\"{}\"", opaque)
+    } else {
+        format!(
+            "fill=\"{}\" fill-opacity=\"{}\"",
+            opaque,
+            color.a as f64 / 255.0
+        )
     }
+}
 
-    fn round_size(&self, value: f64) -> f64 {
-        if self.options.dots_options.round_size {
-            value.floor()
-        } else {
-            value
+/// Intrinsic size of a raster or SVG image.
+fn image_size(data: &[u8]) -> Option<(f64, f64)> {
+    if is_svg(data) {
+        let tree = resvg::usvg::Tree::from_data(data, &resvg::usvg::Options::default()).ok()?;
+        let size = tree.size();
+        return Some((size.width() as f64, size.height() as f64));
+    }
+    let (width, height) = image::ImageReader::new(std::io::Cursor::new(data))
+        .with_guessed_format()
+        .ok()?
+        .into_dimensions()
+        .ok()?;
+    Some((width as f64, height as f64))
+}
+
+/// Encode image bytes as a base64 data URL, detecting the mime type.
+fn data_url(image_data: &[u8]) -> String {
+    let base64_data =
+        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, image_data);
+
+    let mime_type = if image_data.starts_with(&[0x89, 0x50, 0x4E, 0x47]) {
+        "image/png"
+    } else if image_data.starts_with(&[0xFF, 0xD8]) {
+        "image/jpeg"
+    } else if image_data.starts_with(b"RIFF")
+        && image_data.len() > 12
+        && &image_data[8..12] == b"WEBP"
+    {
+        "image/webp"
+    } else if is_svg(image_data) {
+        "image/svg+xml"
+    } else {
+        "image/png" // Default
+    };
+
+    format!("data:{};base64,{}", mime_type, base64_data)
+}
+
+/// Whether `data` looks like an SVG document.
+fn is_svg(data: &[u8]) -> bool {
+    let text = String::from_utf8_lossy(&data[..data.len().min(512)]);
+    let text = text.trim_start_matches('\u{feff}').trim_start();
+    text.starts_with("<svg") || (text.starts_with("<?xml") && text.contains("<svg"))
+}
+
+/// Whether the module at `row`/`col` belongs to an alignment pattern
+/// centered at one of `units`.
+fn in_alignment_unit(units: &[(usize, usize)], row: usize, col: usize) -> bool {
+    units
+        .iter()
+        .any(|&(r, c)| row.abs_diff(r) <= 2 && col.abs_diff(c) <= 2)
+}
+
+/// Rotation and optional mirror transform for a finder pattern centered
+/// at `cx`/`cy`.
+fn corner_orientation(
+    orientation: CornerOrientation,
+    corner: FinderCorner,
+    cx: f64,
+    cy: f64,
+) -> (f64, Option<String>) {
+    match (orientation, corner) {
+        (_, FinderCorner::TopLeft) | (CornerOrientation::Uniform, _) => (0.0, None),
+        (CornerOrientation::Rotated, FinderCorner::TopRight) => (PI / 2.0, None),
+        (CornerOrientation::Rotated, FinderCorner::BottomLeft) => (-PI / 2.0, None),
+        (CornerOrientation::Mirrored, FinderCorner::TopRight) => {
+            (0.0, Some(format!("matrix(-1,0,0,1,{},0)", 2.0 * cx)))
+        }
+        (CornerOrientation::Mirrored, FinderCorner::BottomLeft) => {
+            (0.0, Some(format!("matrix(1,0,0,-1,0,{})", 2.0 * cy)))
         }
     }
 }
+
+/// Scale path data drawn in a `units`×`units` box over the square at
+/// `x`/`y`.
+fn artwork_path(d: &str, units: f64, x: f64, y: f64, size: f64, rotation: f64) -> String {
+    let mut transform = format!("translate({},{}) scale({})", x, y, size / units);
+    if let Some(rotate) = rotate_transform(x, y, size, rotation) {
+        transform = format!("{} {}", rotate, transform);
+    }
+    svg_path(d, Some("evenodd"), Some(&transform))
+}
+
+/// Stretch an image over the square at `x`/`y`.
+fn artwork_image(
+    image: &[u8],
+    x: f64,
+    y: f64,
+    size: f64,
+    rotation: f64,
+    mirror: Option<&str>,
+) -> String {
+    let url = data_url(image);
+    let mut element = format!(
+        r#"<image href="{}" x="{}" y="{}" width="{}" height="{}" preserveAspectRatio="none"/>"#,
+        url, x, y, size, size
+    );
+    if let Some(rotate) = rotate_transform(x, y, size, rotation) {
+        element = prepend_transform(&element, &rotate);
+    }
+    let mut element = mirrored(element, mirror);
+    element.push('\n');
+    element
+}
+
+/// Apply a finder pattern's mirror transform to a drawn element.
+fn mirrored(element: String, mirror: Option<&str>) -> String {
+    match mirror {
+        Some(transform) => prepend_transform(&element, transform),
+        None => element,
+    }
+}
+
+/// Wrap a finder pattern's shapes and fill in its mirror transform, so
+/// gradients and patterns flip along with the shape.
+fn mirrored_group(elements: String, mirror: Option<&str>) -> String {
+    match mirror {
+        Some(transform) => format!("<g transform=\"{}\">\n{}</g>\n", transform, elements),
+        None => elements,
+    }
+}
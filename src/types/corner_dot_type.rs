@@ -1,6 +1,9 @@
 //! QR code corner dot type variants.
 
 /// Defines the visual style for QR code corner dots (center of finder patterns).
+///
+/// Every style reaches the middle of each edge of its 3×3 area, so the
+/// 1:1:3:1:1 profile through the pattern center is kept.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
@@ -10,11 +13,40 @@ pub enum CornerDotType {
     Dot,
     /// Square dot.
     Square,
+    /// Square with all corners rounded by the options' radius.
+    Rounded,
+    /// Top-left and bottom-right corners rounded, like a leaf.
+    Leaf,
+    /// Diamond touching the middle of each edge.
+    Diamond,
+    /// Four-pointed star, pinched more as the options' radius grows (up to 0.7).
+    Star,
+    /// Heart with its point at the bottom.
+    Heart,
+    /// Drawn from `CornersDotOptions::artwork` (square if none is set).
+    Custom,
 }
 
 impl CornerDotType {
-    /// Returns all available corner dot types.
+    /// Returns all built-in corner dot types.
     pub fn all() -> &'static [CornerDotType] {
-        &[CornerDotType::Dot, CornerDotType::Square]
+        &[
+            CornerDotType::Dot,
+            CornerDotType::Square,
+            CornerDotType::Rounded,
+            CornerDotType::Leaf,
+            CornerDotType::Diamond,
+            CornerDotType::Star,
+            CornerDotType::Heart,
+        ]
+    }
+
+    /// Largest radius the detector still reads in a finder pattern; corner
+    /// dots are drawn with at most this radius.
+    pub fn max_radius(&self) -> f64 {
+        match self {
+            CornerDotType::Star => 0.7,
+            _ => 1.0,
+        }
     }
 }
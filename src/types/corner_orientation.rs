@@ -0,0 +1,28 @@
+//! Finder pattern orientation modes.
+
+/// How the three finder patterns are turned relative to the top-left one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum CornerOrientation {
+    /// All three drawn the same way up.
+    Uniform,
+    /// Top-right turned 90° clockwise and bottom-left 90° counter-clockwise
+    /// (default).
+    #[default]
+    Rotated,
+    /// Top-right mirrored left to right and bottom-left top to bottom, so
+    /// each pattern points away from the center.
+    Mirrored,
+}
+
+impl CornerOrientation {
+    /// Returns all available orientation modes.
+    pub fn all() -> &'static [CornerOrientation] {
+        &[
+            CornerOrientation::Uniform,
+            CornerOrientation::Rotated,
+            CornerOrientation::Mirrored,
+        ]
+    }
+}
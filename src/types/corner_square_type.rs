@@ -1,6 +1,12 @@
 //! QR code corner square type variants.
 
 /// Defines the visual style for QR code corner squares (finder patterns).
+///
+/// Every style keeps the one-module ring, so the 1:1:3:1:1 profile through
+/// the pattern center is unchanged. The detector behind
+/// [`verify`](crate::QRCodeStyling::verify) fits the grid to the outer
+/// corners, though, so it can't read `Dot` or `ExtraRounded`, and finder
+/// radii are capped at [`max_radius`](Self::max_radius).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
@@ -12,15 +18,45 @@ pub enum CornerSquareType {
     Dot,
     /// Extra rounded corner pattern.
     ExtraRounded,
+    /// Square with all corners rounded by the options' radius.
+    Rounded,
+    /// Top-left and bottom-right corners rounded, like a leaf.
+    Leaf,
+    /// Square with corners cut off by the options' radius.
+    Octagon,
+    /// Ring of 24 separate dots, one per module.
+    DotsRing,
+    /// Drawn from `CornersSquareOptions::artwork` (square if none is set).
+    Custom,
 }
 
 impl CornerSquareType {
-    /// Returns all available corner square types.
+    /// Returns all built-in corner square types.
     pub fn all() -> &'static [CornerSquareType] {
         &[
             CornerSquareType::Square,
             CornerSquareType::Dot,
             CornerSquareType::ExtraRounded,
+            CornerSquareType::Rounded,
+            CornerSquareType::Leaf,
+            CornerSquareType::Octagon,
+            CornerSquareType::DotsRing,
         ]
     }
+
+    /// Largest radius the detector can still fit the grid to; finder
+    /// patterns are drawn with at most this radius.
+    pub fn max_radius(&self) -> f64 {
+        match self {
+            CornerSquareType::Rounded | CornerSquareType::Leaf => 0.3,
+            CornerSquareType::Octagon => 0.1,
+            _ => 1.0,
+        }
+    }
+
+    /// Returns true if the detector can't locate finder patterns of this
+    /// shape, whatever the radius.
+    pub fn is_round(&self) -> bool {
+        matches!(self, CornerSquareType::Dot | CornerSquareType::ExtraRounded)
+    }
 }
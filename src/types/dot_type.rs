@@ -18,10 +18,29 @@ pub enum DotType {
     ClassyRounded,
     /// Extra rounded corner style.
     ExtraRounded,
+    /// Diamond touching the middle of each module edge.
+    Diamond,
+    /// Five-pointed star.
+    Star,
+    /// Heart.
+    Heart,
+    /// Pointy-top hexagon.
+    Hexagon,
+    /// Plus sign.
+    Cross,
+    /// Vertical runs of dark modules fused into pill-shaped strokes.
+    VerticalBars,
+    /// Horizontal runs of dark modules fused into pill-shaped strokes.
+    HorizontalBars,
+    /// Connected modules merged into blobs with rounded outer corners and
+    /// filleted inner corners, including diagonal neighbors.
+    Liquid,
+    /// Drawn by `DotsOptions::custom_drawer`, which must be set.
+    Custom,
 }
 
 impl DotType {
-    /// Returns all available dot types.
+    /// Returns all built-in dot types.
     pub fn all() -> &'static [DotType] {
         &[
             DotType::Square,
@@ -30,6 +49,14 @@ impl DotType {
             DotType::Classy,
             DotType::ClassyRounded,
             DotType::ExtraRounded,
+            DotType::Diamond,
+            DotType::Star,
+            DotType::Heart,
+            DotType::Hexagon,
+            DotType::Cross,
+            DotType::VerticalBars,
+            DotType::HorizontalBars,
+            DotType::Liquid,
         ]
     }
 }
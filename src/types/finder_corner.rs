@@ -0,0 +1,32 @@
+//! Finder pattern positions.
+
+/// One of the three finder patterns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum FinderCorner {
+    /// Top-left finder pattern.
+    TopLeft,
+    /// Top-right finder pattern.
+    TopRight,
+    /// Bottom-left finder pattern.
+    BottomLeft,
+}
+
+impl FinderCorner {
+    /// All corners in drawing order.
+    pub const ALL: [FinderCorner; 3] = [
+        FinderCorner::TopLeft,
+        FinderCorner::TopRight,
+        FinderCorner::BottomLeft,
+    ];
+
+    /// Top-left module (row, column) of this finder pattern.
+    pub fn origin(&self, count: usize) -> (usize, usize) {
+        match self {
+            FinderCorner::TopLeft => (0, 0),
+            FinderCorner::TopRight => (0, count - 7),
+            FinderCorner::BottomLeft => (count - 7, 0),
+        }
+    }
+}
@@ -10,4 +10,6 @@ pub enum GradientType {
     Linear,
     /// Radial gradient.
     Radial,
+    /// Conic (sweep) gradient around a center point.
+    Conic,
 }
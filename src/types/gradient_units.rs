@@ -0,0 +1,15 @@
+//! Coordinate systems for explicit gradient geometry.
+
+/// How gradient centers, radii and points are measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum GradientUnits {
+    /// Fractions of the painted area: the whole canvas for dots and the
+    /// background, each pattern's box for finder patterns (default). Radii
+    /// are fractions of the area's longer side.
+    #[default]
+    Relative,
+    /// Pixels on the canvas.
+    Absolute,
+}
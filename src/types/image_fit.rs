@@ -0,0 +1,17 @@
+//! Image fit modes.
+
+/// How an image is fitted to the area it is drawn in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum ImageFit {
+    /// Scale to fill the area, cropping the overflow (default).
+    #[default]
+    Cover,
+    /// Scale to fit inside the area, leaving empty bands.
+    Contain,
+    /// Stretch to the area, ignoring the aspect ratio.
+    Stretch,
+    /// Repeat at the image's own size from the top-left corner.
+    Tile,
+}
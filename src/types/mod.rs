@@ -3,7 +3,12 @@
 mod dot_type;
 mod corner_dot_type;
 mod corner_square_type;
+mod corner_orientation;
+mod finder_corner;
 mod gradient_type;
+mod gradient_units;
+mod spread_method;
+mod image_fit;
 mod output_format;
 mod shape_type;
 mod error_correction;
@@ -12,7 +17,12 @@ mod mode;
 pub use dot_type::DotType;
 pub use corner_dot_type::CornerDotType;
 pub use corner_square_type::CornerSquareType;
+pub use corner_orientation::CornerOrientation;
+pub use finder_corner::FinderCorner;
 pub use gradient_type::GradientType;
+pub use gradient_units::GradientUnits;
+pub use spread_method::SpreadMethod;
+pub use image_fit::ImageFit;
 pub use output_format::OutputFormat;
 pub use shape_type::ShapeType;
 pub use error_correction::ErrorCorrectionLevel;
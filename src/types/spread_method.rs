@@ -0,0 +1,26 @@
+//! Gradient spread methods.
+
+/// How a gradient continues past its first and last stop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum SpreadMethod {
+    /// Extend the end colors (default).
+    #[default]
+    Pad,
+    /// Repeat the gradient, mirrored every other time.
+    Reflect,
+    /// Repeat the gradient.
+    Repeat,
+}
+
+impl SpreadMethod {
+    /// Value of the SVG `spreadMethod` attribute.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SpreadMethod::Pad => "pad",
+            SpreadMethod::Reflect => "reflect",
+            SpreadMethod::Repeat => "repeat",
+        }
+    }
+}
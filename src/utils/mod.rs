@@ -1,5 +1,7 @@
 //! Utility functions.
 
 mod calculate_image_size;
+mod rng;
 
 pub use calculate_image_size::calculate_image_size;
+pub use rng::SeededRng;